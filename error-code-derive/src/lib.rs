@@ -0,0 +1,351 @@
+//! Derive macro for [error-code](https://crates.io/crates/error-code) categories.
+//!
+//! Refer to `error_code::ErrorCategory` for documentation.
+
+#![warn(missing_docs)]
+#![allow(clippy::style)]
+
+extern crate proc_macro;
+
+use proc_macro::{TokenStream, TokenTree, Delimiter, Spacing};
+
+use core::fmt::Write;
+
+struct Variant {
+    name: String,
+    message: String,
+    ///`#[cfg(...)]` attributes to put on every item generated for variant.
+    cfgs: String,
+}
+
+struct Enum {
+    name: String,
+    variants: Vec<Variant>,
+}
+
+fn compile_error(error: &str) -> TokenStream {
+    format!("compile_error!({:?});", error).parse().expect("To parse compile_error")
+}
+
+#[inline]
+fn is_punct(token: &TokenTree, expected: char) -> bool {
+    match token {
+        TokenTree::Punct(punct) => punct.as_char() == expected,
+        _ => false,
+    }
+}
+
+#[inline]
+fn is_ident(token: &TokenTree, expected: &str) -> bool {
+    match token {
+        TokenTree::Ident(ident) => ident.to_string() == expected,
+        _ => false,
+    }
+}
+
+///Extracts value of string literal, returning `None` if it is not a string literal.
+fn parse_str_literal(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let text = &raw[hashes..];
+        let text = text.strip_prefix('"')?;
+        let text = text.strip_suffix(&raw[..hashes])?;
+        return text.strip_suffix('"').map(ToOwned::to_owned);
+    }
+
+    let text = literal.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            't' => result.push('\t'),
+            '0' => result.push('\0'),
+            '\\' => result.push('\\'),
+            '\'' => result.push('\''),
+            '"' => result.push('"'),
+            'x' => {
+                let code = [chars.next()?, chars.next()?].iter().collect::<String>();
+                result.push(u8::from_str_radix(&code, 16).ok()? as char);
+            },
+            'u' => {
+                let code = chars.by_ref().skip(1).take_while(|ch| *ch != '}').filter(|ch| *ch != '_').collect::<String>();
+                result.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            },
+            //Line continuation skips all following whitespace
+            '\n' => {
+                let rest = chars.as_str().trim_start();
+                chars = rest.chars();
+            },
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+///Parses attribute of form `#[name = "literal"]`, returning its name and value.
+fn parse_name_value_attr(attr: TokenStream) -> Result<Option<(String, String)>, String> {
+    let mut tokens = attr.into_iter();
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(name)) => name.to_string(),
+        _ => return Ok(None),
+    };
+
+    match name.as_str() {
+        "doc" | "message" => (),
+        _ => return Ok(None),
+    }
+
+    //Other forms of doc attribute (e.g. `#[doc(hidden)]`) carry no text
+    let is_doc = name == "doc";
+    match tokens.next() {
+        Some(ref token) if is_punct(token, '=') => (),
+        _ if is_doc => return Ok(None),
+        _ => return Err(format!("#[{}] attribute must be of form #[{} = \"...\"]", name, name)),
+    }
+
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => match parse_str_literal(&literal.to_string()) {
+            Some(value) => Ok(Some((name, value))),
+            None if is_doc => Ok(None),
+            None => Err(format!("#[{}] attribute value must be string literal", name)),
+        },
+        _ if is_doc => Ok(None),
+        _ => Err(format!("#[{}] attribute value must be string literal", name)),
+    }
+}
+
+///Builds message out of doc comments, using first paragraph only.
+fn doc_to_message(docs: &[String]) -> String {
+    let mut message = String::new();
+    for line in docs.join("\n").lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            if message.is_empty() {
+                continue;
+            } else {
+                break;
+            }
+        }
+
+        if !message.is_empty() {
+            message.push(' ');
+        }
+        message.push_str(line);
+    }
+    message
+}
+
+fn parse_variant(tokens: Vec<TokenTree>) -> Result<Variant, String> {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut docs = Vec::new();
+    let mut message = None;
+    let mut cfgs = String::new();
+
+    while let Some(token) = tokens.peek() {
+        if !is_punct(token, '#') {
+            break;
+        }
+        tokens.next();
+
+        match tokens.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket && group.stream().into_iter().next().map_or(false, |token| is_ident(&token, "cfg")) => {
+                let _ = write!(cfgs, "#[{}] ", group.stream());
+            },
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => match parse_name_value_attr(group.stream())? {
+                Some((name, value)) => match name.as_str() {
+                    "doc" => docs.push(value),
+                    _ => {
+                        if message.is_some() {
+                            return Err("#[message] attribute is specified more than once".to_owned());
+                        }
+                        message = Some(value);
+                    }
+                },
+                None => continue,
+            },
+            _ => return Err("Invalid attribute".to_owned()),
+        }
+    }
+
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(name)) => name.to_string(),
+        _ => return Err("Expected variant name".to_owned()),
+    };
+
+    match tokens.next() {
+        None => (),
+        Some(ref token) if is_punct(token, '=') => (),
+        Some(_) => return Err(format!("Variant '{}' must not have fields", name)),
+    }
+
+    let message = match message {
+        Some(message) => message,
+        None => doc_to_message(&docs),
+    };
+
+    if message.is_empty() {
+        return Err(format!("Variant '{}' has no message, add doc comment or #[message = \"...\"]", name));
+    }
+
+    Ok(Variant {
+        name,
+        message,
+        cfgs,
+    })
+}
+
+fn parse_enum(input: TokenStream) -> Result<Enum, String> {
+    let mut tokens = input.into_iter();
+
+    //Skip attributes and visibility
+    loop {
+        match tokens.next() {
+            Some(ref token) if is_ident(token, "enum") => break,
+            Some(ref token) if is_ident(token, "struct") || is_ident(token, "union") => return Err("ErrorCategory can only be derived for enum".to_owned()),
+            Some(_) => continue,
+            None => return Err("ErrorCategory can only be derived for enum".to_owned()),
+        }
+    }
+
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(name)) => name.to_string(),
+        _ => return Err("Expected enum name".to_owned()),
+    };
+
+    let body = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+        Some(ref token) if is_punct(token, '<') || is_ident(token, "where") => return Err("ErrorCategory cannot be derived for generic enum".to_owned()),
+        _ => return Err("Expected enum body".to_owned()),
+    };
+
+    let mut variants = Vec::new();
+    let mut variant = Vec::new();
+    for token in body {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == ',' && punct.spacing() == Spacing::Alone => {
+                variants.push(parse_variant(core::mem::take(&mut variant))?);
+            },
+            token => variant.push(token),
+        }
+    }
+    if !variant.is_empty() {
+        variants.push(parse_variant(variant)?);
+    }
+
+    if variants.is_empty() {
+        return Err("ErrorCategory cannot be derived for enum without variants".to_owned());
+    }
+
+    Ok(Enum {
+        name,
+        variants,
+    })
+}
+
+fn generate(input: Enum) -> String {
+    let Enum { name, variants } = input;
+    let mut out = String::new();
+
+    let _ = write!(out, "impl {name} {{
+    #[inline]
+    ///Map raw error code to textual representation.
+    pub fn map_code(code: ::error_code::types::c_int) -> ::core::option::Option<&'static str> {{
+", name = name);
+    for (idx, variant) in variants.iter().enumerate() {
+        let _ = writeln!(out, "        {cfgs}const __ERROR_CODE_{idx}: ::error_code::types::c_int = {name}::{variant} as ::error_code::types::c_int;", cfgs = variant.cfgs, idx = idx, name = name, variant = variant.name);
+    }
+    out.push_str("        match code {\n");
+    for (idx, variant) in variants.iter().enumerate() {
+        let _ = writeln!(out, "            {cfgs}__ERROR_CODE_{idx} => ::core::option::Option::Some({message:?}),", cfgs = variant.cfgs, idx = idx, message = variant.message);
+    }
+    let _ = write!(out, "            _ => ::core::option::Option::None,
+        }}
+    }}
+
+    #[inline(always)]
+    ///Converts into error code
+    pub fn into_error_code(self) -> ::error_code::ErrorCode {{
+        <Self as ::error_code::ErrorCategory>::error_code(self as ::error_code::types::c_int)
+    }}
+}}
+
+impl ::error_code::ErrorCategory for {name} {{
+    const NAME: &'static str = {name:?};
+
+    #[inline(always)]
+    fn category() -> &'static ::error_code::Category {{
+        static CATEGORY: ::error_code::Category = <{name} as ::error_code::ErrorCategory>::DEFINITION;
+        &CATEGORY
+    }}
+
+    fn write_message(code: ::error_code::types::c_int, out: &mut dyn ::core::fmt::Write) -> ::core::fmt::Result {{
+        match Self::map_code(code) {{
+            ::core::option::Option::Some(msg) => out.write_str(msg),
+            ::core::option::Option::None => out.write_str(::error_code::utils::generic_map_error_code(code)),
+        }}
+    }}
+}}
+
+impl ::core::convert::From<{name}> for ::error_code::ErrorCode {{
+    #[inline(always)]
+    fn from(this: {name}) -> ::error_code::ErrorCode {{
+        this.into_error_code()
+    }}
+}}
+
+impl ::core::fmt::Display for {name} {{
+    fn fmt(&self, fmt: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {{
+        let code = match self {{
+", name = name);
+    for variant in variants.iter() {
+        let _ = writeln!(out, "            {cfgs}{name}::{variant} => {name}::{variant} as ::error_code::types::c_int,", cfgs = variant.cfgs, name = name, variant = variant.name);
+    }
+    let _ = write!(out, "        }};
+        ::core::fmt::Display::fmt(&<Self as ::error_code::ErrorCategory>::error_code(code), fmt)
+    }}
+}}
+");
+
+    out
+}
+
+///Implements `ErrorCategory` for field-less enum, alongside with conversion into `ErrorCode`.
+///
+///Message of each variant is taken from `#[message = "..."]` attribute, if present, otherwise from
+///the first paragraph of its documentation, joining multiple lines with space.
+///
+///`#[cfg(...)]` attributes of variant are applied to the code generated for it.
+///
+///Only enums without fields can be used:
+///
+///```compile_fail
+///#[derive(error_code_derive::ErrorCategory)]
+///enum MyError {
+///    ///Has field
+///    Field(u8),
+///}
+///```
+///
+///Every variant must have message:
+///
+///```compile_fail
+///#[derive(error_code_derive::ErrorCategory)]
+///enum MyError {
+///    NoMessage = 1,
+///}
+///```
+#[proc_macro_derive(ErrorCategory, attributes(message))]
+pub fn error_category(input: TokenStream) -> TokenStream {
+    match parse_enum(input) {
+        Ok(input) => generate(input).parse().expect("To generate valid code"),
+        Err(error) => compile_error(&error),
+    }
+}
@@ -0,0 +1,103 @@
+use error_code::{ErrorCategory, ErrorCode};
+
+#[derive(ErrorCategory, Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u16)]
+#[non_exhaustive]
+pub(crate) enum DeviceError {
+    ///Device is busy
+    Busy = 1,
+    ///Device is not connected,
+    ///check the cable.
+    ///
+    ///Second paragraph is not part of message.
+    NotConnected = 2,
+    ///Documentation is not used as message
+    #[message = "Firmware is \"outdated\" \u{2014} update it"]
+    Firmware = 3,
+    #[doc = r"Raw string with \ backslash"]
+    Raw = 0x100,
+    ///Hidden variant
+    #[doc(hidden)]
+    Hidden = 0x101,
+}
+
+#[derive(ErrorCategory)]
+enum NegativeError {
+    ///Negative
+    Negative = -1,
+    ///Implicit
+    Implicit,
+}
+
+#[derive(ErrorCategory)]
+enum CfgError {
+    ///Enabled
+    Enabled = 1,
+    ///Disabled
+    #[cfg(any())]
+    Disabled = 2,
+    ///Unix only
+    #[cfg(unix)]
+    Unix = 3,
+}
+
+#[test]
+fn check_derive_messages() {
+    assert_eq!(DeviceError::map_code(1), Some("Device is busy"));
+    assert_eq!(DeviceError::map_code(2), Some("Device is not connected, check the cable."));
+    assert_eq!(DeviceError::map_code(3), Some("Firmware is \"outdated\" — update it"));
+    assert_eq!(DeviceError::map_code(0x100), Some("Raw string with \\ backslash"));
+    assert_eq!(DeviceError::map_code(4), None);
+    assert_eq!(DeviceError::map_code(0x101), Some("Hidden variant"));
+
+    assert_eq!(DeviceError::Busy.to_string(), "DeviceError(1): Device is busy");
+    assert_eq!(DeviceError::Raw.to_string(), "DeviceError(256): Raw string with \\ backslash");
+    assert_eq!(format!("{:?}", DeviceError::Busy), "Busy");
+
+    let error = ErrorCode::new(4, DeviceError::category());
+    assert_eq!(error.message(), error_code::utils::generic_map_error_code(4));
+
+    assert_eq!(NegativeError::Negative.to_string(), "NegativeError(-1): Negative");
+    assert_eq!(NegativeError::Implicit.to_string(), "NegativeError(0): Implicit");
+
+    assert_eq!(CfgError::map_code(1), Some("Enabled"));
+    assert_eq!(CfgError::map_code(2), None);
+    assert_eq!(CfgError::Enabled.to_string(), "CfgError(1): Enabled");
+    #[cfg(unix)]
+    assert_eq!(CfgError::Unix.to_string(), "CfgError(3): Unix only");
+}
+
+#[test]
+fn check_derive_error_code() {
+    let error: ErrorCode = DeviceError::NotConnected.into();
+    assert_eq!(error.raw_code(), 2);
+    assert_eq!(error.category().name, "DeviceError");
+    assert_eq!(error, DeviceError::NotConnected.into_error_code());
+    assert_eq!(error, DeviceError::error_code(2));
+    assert_ne!(error, DeviceError::Busy.into_error_code());
+    assert_ne!(error, NegativeError::Implicit.into_error_code());
+    assert!(!error.is_would_block());
+
+    fn handle(res: Result<(), DeviceError>) -> Result<(), ErrorCode> {
+        res?;
+        Ok(())
+    }
+    assert_eq!(handle(Err(DeviceError::Firmware)).unwrap_err(), DeviceError::Firmware.into_error_code());
+}
+
+mod other {
+    #[derive(error_code::ErrorCategory)]
+    pub enum DeviceError {
+        ///Other device is busy
+        Busy = 1,
+    }
+}
+
+#[test]
+fn check_derive_same_name() {
+    let error = DeviceError::Busy.into_error_code();
+    let other = other::DeviceError::Busy.into_error_code();
+    assert_eq!(error.category().name, other.category().name);
+    assert_ne!(error, other);
+    assert_ne!(other, error);
+}
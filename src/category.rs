@@ -0,0 +1,136 @@
+use crate::{Category, ErrorCode, MessageBuf};
+use crate::utils::MessageWriter;
+use crate::types::c_int;
+
+use core::{fmt, ptr};
+
+///Trait based definition of error category.
+///
+///Alternative to manually filling [Category](struct.Category.html) with function pointers, which
+///allows to rely on default implementation of optional hooks.
+///
+///Category is identified by address of `static`, hence implementation must store
+///[DEFINITION](#associatedconstant.DEFINITION) in `static` and return it from
+///[category](#tymethod.category).
+///
+///## Usage
+///
+///```rust
+///use error_code::{Category, ErrorCategory, ErrorCode};
+///use error_code::types::c_int;
+///
+///use core::fmt;
+///
+///struct DeviceError;
+///
+///impl ErrorCategory for DeviceError {
+///    const NAME: &'static str = "DeviceError";
+///
+///    fn category() -> &'static Category {
+///        static CATEGORY: Category = <DeviceError as ErrorCategory>::DEFINITION;
+///        &CATEGORY
+///    }
+///
+///    fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+///        write!(out, "Device failed with {}", code)
+///    }
+///
+///    fn is_would_block(code: c_int) -> bool {
+///        code == 1
+///    }
+///}
+///
+///let error = DeviceError::error_code(2);
+///assert_eq!(error.to_string(), "DeviceError(2): Device failed with 2");
+///assert!(!error.is_would_block());
+///assert!(ErrorCode::new(1, DeviceError::category()).is_would_block());
+///```
+///
+///## Derive
+///
+///With `derive` feature, trait can be derived for field-less enum, similarly to [define_category](macro.define_category.html).
+///
+///Message of each variant is taken from `#[message = "..."]` attribute, if present, otherwise from
+///the first paragraph of its documentation, joining multiple lines with space.
+///
+///```rust
+///# #[cfg(feature = "derive")] {
+///use error_code::{ErrorCategory, ErrorCode};
+///
+///#[derive(ErrorCategory, Copy, Clone, Debug, PartialEq, Eq)]
+///#[repr(u8)]
+///#[non_exhaustive]
+///pub(crate) enum MyError {
+///    ///Device is busy,
+///    ///try again later.
+///    ///
+///    ///Details are not part of the message.
+///    Busy = 1,
+///    ///Not used as message
+///    #[message = "Device is gone"]
+///    Gone = 2,
+///}
+///
+///assert_eq!(MyError::Busy.to_string(), "MyError(1): Device is busy, try again later.");
+///assert_eq!(ErrorCode::from(MyError::Gone).message(), "Device is gone");
+///assert_eq!(MyError::map_code(3), None);
+///# }
+///```
+pub trait ErrorCategory: 'static {
+    ///Category name
+    const NAME: &'static str;
+
+    ///Category, which uses this trait implementation for all of its hooks.
+    ///
+    ///Must be stored in `static` to be returned by [category](#tymethod.category).
+    const DEFINITION: Category = Category {
+        name: Self::NAME,
+        message: message::<Self>,
+        equivalent: equivalent::<Self>,
+        is_would_block: Self::is_would_block,
+        write_message: Some(Self::write_message),
+    };
+
+    ///Returns category, stored in `static` initialized with [DEFINITION](#associatedconstant.DEFINITION).
+    fn category() -> &'static Category;
+
+    ///Writes descriptive message of the error code into `out`.
+    fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result;
+
+    #[inline(always)]
+    ///Returns `true` if supplied error code indicates WouldBlock like error.
+    ///
+    ///By default no error code is considered as such.
+    fn is_would_block(_code: c_int) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    ///Checks whether error code is equivalent to error code of another category.
+    ///
+    ///Error codes of the same category are always compared by raw code, hence this is only called
+    ///for codes of another category, and by default returns `false`.
+    fn equivalent(_code: c_int, _other: &ErrorCode) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    ///Creates new error code of this category.
+    fn error_code(code: c_int) -> ErrorCode {
+        ErrorCode::new(code, Self::category())
+    }
+}
+
+fn message<T: ErrorCategory + ?Sized>(code: c_int, out: &mut MessageBuf) -> &str {
+    let mut writer = MessageWriter::new(out);
+    let _ = T::write_message(code, &mut writer);
+    writer.finish()
+}
+
+fn equivalent<T: ErrorCategory + ?Sized>(code: c_int, other: &ErrorCode) -> bool {
+    if ptr::eq(T::category(), other.category()) {
+        code == other.raw_code()
+    } else {
+        T::equivalent(code, other)
+    }
+}
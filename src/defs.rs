@@ -1,108 +1,1907 @@
 //! Definitions used by this library
+//!
+//!Constants at the root of the module correspond to the target platform.
+//!Error codes of other platforms are available via corresponding sub-modules.
 
-#[allow(unused)]
 use crate::types::c_int;
 
-#[cfg(any(target_env = "wasi", target_os = "wasi"))]
-///EAGAIN
-pub const EAGAIN: c_int = 6;
-#[cfg(any(target_env = "wasi", target_os = "wasi"))]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = EAGAIN;
-
-#[cfg(windows)]
-///EAGAIN
-pub const EAGAIN: c_int = 11;
-#[cfg(windows)]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = 140;
-
-#[cfg(target_os = "fuchsia")]
-///EAGAIN
-pub const EAGAIN: c_int = 11;
-#[cfg(target_os = "fuchsia")]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = EAGAIN;
-
-#[cfg(target_os = "solid_asp3")]
-///EAGAIN
-pub const EAGAIN: c_int = 11;
-#[cfg(target_os = "solid_asp3")]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = EAGAIN;
-
-#[cfg(target_os = "vxworks")]
-///EAGAIN
-pub const EAGAIN: c_int = 11;
-#[cfg(target_os = "vxworks")]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = 70;
-
-#[cfg(target_os = "teeos")]
-///EAGAIN
-pub const EAGAIN: c_int = 11;
-#[cfg(target_os = "teeos")]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = EAGAIN;
-
-//---------------------
-//unix
-//---------------------
-#[cfg(
-    any(
-        target_env = "newlib",
-        target_os = "solaris", target_os = "illumos", target_os = "nto",
-        target_os = "aix", target_os = "android", target_os = "linux",
-        target_os = "l4re"
-    )
-)]
-///EAGAIN
-pub const EAGAIN: c_int = 11;
-#[cfg(
-    any(
-        target_os = "solaris", target_os = "illumos", target_os = "nto",
-        target_os = "aix", target_os = "android", target_os = "linux",
-        target_os = "l4re"
-    )
-)]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = EAGAIN;
-#[cfg(
-    any(
-        target_os = "macos", target_os = "ios", target_os = "tvos",
-        target_os = "watchos", target_os = "freebsd", target_os = "dragonfly",
-        target_os = "openbsd", target_os = "netbsd"
-    )
-)]
-///EAGAIN
-pub const EAGAIN: c_int = 35;
-#[cfg(
-    any(
-        target_os = "macos", target_os = "ios", target_os = "tvos",
-        target_os = "watchos", target_os = "freebsd", target_os = "dragonfly",
-        target_os = "openbsd", target_os = "netbsd"
-    )
-)]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = EAGAIN;
-
-#[cfg(target_os = "redox")]
-///EAGAIN
-pub const EAGAIN: c_int = 11;
-#[cfg(target_os = "redox")]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = 41;
-
-#[cfg(target_os = "haiku")]
-///EAGAIN
-pub const EAGAIN: c_int = -2147483637;
-#[cfg(target_os = "haiku")]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = EAGAIN;
-
-#[cfg(target_os = "emscripten")]
-///EAGAIN
-pub const EAGAIN: c_int = 6;
-#[cfg(target_os = "emscripten")]
-///EWOULDBLOCK
-pub const EWOULDBLOCK: c_int = EAGAIN;
+macro_rules! define_errno {
+    ($(#[unsupported($($unsupported:ident),+)])? $($name:ident = $value:expr,)+ $(; $($alias:ident => $canonical:ident,)+)?) => {
+        $(
+            #[doc = stringify!($name)]
+            pub const $name: c_int = $value;
+        )+
+
+        #[allow(dead_code)]
+        ///Values of all error codes known by `posix::Errno`, with `None` for codes that are not supported.
+        pub(crate) mod value {
+            use super::c_int;
+
+            $(
+                pub(crate) const $name: Option<c_int> = Some(super::$name);
+            )+
+            $($(
+                pub(crate) const $unsupported: Option<c_int> = None;
+            )+)?
+        }
+
+        #[allow(unreachable_patterns)]
+        pub(crate) const fn normalize(code: c_int) -> c_int {
+            match code {
+                EWOULDBLOCK => EAGAIN,
+                EOPNOTSUPP => ENOTSUP,
+                $($(
+                    $alias => $canonical,
+                )+)?
+                code => code,
+            }
+        }
+    };
+}
+
+macro_rules! define_host {
+    ($($cfg:meta => $module:ident: $platform:ident,)+) => {
+        $(
+            #[cfg($cfg)]
+            pub use self::$module::*;
+            #[cfg($cfg)]
+            pub(crate) const PLATFORM: crate::posix::Platform = crate::posix::Platform::$platform;
+        )+
+
+        //Targets without known numbering use Linux one, which is considered portable.
+        #[cfg(not(any($($cfg),+)))]
+        pub use self::linux::*;
+        #[cfg(not(any($($cfg),+)))]
+        pub(crate) const PLATFORM: crate::posix::Platform = crate::posix::Platform::Linux;
+    };
+}
+
+define_host! {
+    target_os = "wasi" => wasi: Wasi,
+    target_os = "emscripten" => emscripten: Emscripten,
+    windows => windows: Windows,
+    target_os = "solid_asp3" => solid: Solid,
+    target_os = "vxworks" => vxworks: VxWorks,
+    target_env = "newlib" => newlib: Newlib,
+    target_os = "redox" => redox: Redox,
+    target_os = "haiku" => haiku: Haiku,
+    target_os = "aix" => aix: Aix,
+    target_os = "nto" => nto: Nto,
+    any(target_os = "solaris", target_os = "illumos") => solarish: Solarish,
+    any(target_os = "macos", target_os = "ios", target_os = "tvos", target_os = "watchos", target_os = "visionos") => apple: Apple,
+    target_os = "freebsd" => freebsd: FreeBsd,
+    target_os = "dragonfly" => dragonfly: DragonFly,
+    target_os = "openbsd" => openbsd: OpenBsd,
+    target_os = "netbsd" => netbsd: NetBsd,
+    all(target_os = "linux", any(target_arch = "mips", target_arch = "mips32r6", target_arch = "mips64", target_arch = "mips64r6")) => linux_mips: LinuxMips,
+    all(target_os = "linux", any(target_arch = "sparc", target_arch = "sparc64")) => linux_sparc: LinuxSparc,
+    all(target_os = "linux", any(target_arch = "powerpc", target_arch = "powerpc64")) => linux_powerpc: LinuxPowerPc,
+}
+
+///Linux error codes, also used by Android, Fuchsia, L4Re and TEEOS.
+///
+///This numbering is considered portable and used by targets without own numbering.
+///
+///MIPS, SPARC and PowerPC architectures have own numbering, refer to [linux_mips](linux_mips/index.html),
+///[linux_sparc](linux_sparc/index.html) and [linux_powerpc](linux_powerpc/index.html).
+pub mod linux {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 98,
+        EADDRNOTAVAIL = 99,
+        EAFNOSUPPORT = 97,
+        EAGAIN = 11,
+        EALREADY = 114,
+        EBADF = 9,
+        EBADMSG = 74,
+        EBUSY = 16,
+        ECANCELED = 125,
+        ECHILD = 10,
+        ECONNABORTED = 103,
+        ECONNREFUSED = 111,
+        ECONNRESET = 104,
+        EDEADLK = 35,
+        EDESTADDRREQ = 89,
+        EDOM = 33,
+        EDQUOT = 122,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 113,
+        EIDRM = 43,
+        EILSEQ = 84,
+        EINPROGRESS = 115,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 106,
+        EISDIR = 21,
+        ELOOP = 40,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 90,
+        EMULTIHOP = 72,
+        ENAMETOOLONG = 36,
+        ENETDOWN = 100,
+        ENETRESET = 102,
+        ENETUNREACH = 101,
+        ENFILE = 23,
+        ENOBUFS = 105,
+        ENODATA = 61,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 37,
+        ENOLINK = 67,
+        ENOMEM = 12,
+        ENOMSG = 42,
+        ENOPROTOOPT = 92,
+        ENOSPC = 28,
+        ENOSR = 63,
+        ENOSTR = 60,
+        ENOSYS = 38,
+        ENOTCONN = 107,
+        ENOTDIR = 20,
+        ENOTEMPTY = 39,
+        ENOTRECOVERABLE = 131,
+        ENOTSOCK = 88,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 95,
+        EOVERFLOW = 75,
+        EOWNERDEAD = 130,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 71,
+        EPROTONOSUPPORT = 93,
+        EPROTOTYPE = 91,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 116,
+        ETIME = 62,
+        ETIMEDOUT = 110,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = EDEADLK;
+}
+
+///Linux error codes on PowerPC architecture.
+///
+///Same as [linux](../linux/index.html), except `EDEADLOCK` being distinct code.
+pub mod linux_powerpc {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 98,
+        EADDRNOTAVAIL = 99,
+        EAFNOSUPPORT = 97,
+        EAGAIN = 11,
+        EALREADY = 114,
+        EBADF = 9,
+        EBADMSG = 74,
+        EBUSY = 16,
+        ECANCELED = 125,
+        ECHILD = 10,
+        ECONNABORTED = 103,
+        ECONNREFUSED = 111,
+        ECONNRESET = 104,
+        EDEADLK = 35,
+        EDESTADDRREQ = 89,
+        EDOM = 33,
+        EDQUOT = 122,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 113,
+        EIDRM = 43,
+        EILSEQ = 84,
+        EINPROGRESS = 115,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 106,
+        EISDIR = 21,
+        ELOOP = 40,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 90,
+        EMULTIHOP = 72,
+        ENAMETOOLONG = 36,
+        ENETDOWN = 100,
+        ENETRESET = 102,
+        ENETUNREACH = 101,
+        ENFILE = 23,
+        ENOBUFS = 105,
+        ENODATA = 61,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 37,
+        ENOLINK = 67,
+        ENOMEM = 12,
+        ENOMSG = 42,
+        ENOPROTOOPT = 92,
+        ENOSPC = 28,
+        ENOSR = 63,
+        ENOSTR = 60,
+        ENOSYS = 38,
+        ENOTCONN = 107,
+        ENOTDIR = 20,
+        ENOTEMPTY = 39,
+        ENOTRECOVERABLE = 131,
+        ENOTSOCK = 88,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 95,
+        EOVERFLOW = 75,
+        EOWNERDEAD = 130,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 71,
+        EPROTONOSUPPORT = 93,
+        EPROTOTYPE = 91,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 116,
+        ETIME = 62,
+        ETIMEDOUT = 110,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK, which is distinct from `EDEADLK` on PowerPC.
+    pub const EDEADLOCK: c_int = 58;
+}
+
+///Linux error codes on MIPS architecture.
+pub mod linux_mips {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 125,
+        EADDRNOTAVAIL = 126,
+        EAFNOSUPPORT = 124,
+        EAGAIN = 11,
+        EALREADY = 149,
+        EBADF = 9,
+        EBADMSG = 77,
+        EBUSY = 16,
+        ECANCELED = 158,
+        ECHILD = 10,
+        ECONNABORTED = 130,
+        ECONNREFUSED = 146,
+        ECONNRESET = 131,
+        EDEADLK = 45,
+        EDESTADDRREQ = 96,
+        EDOM = 33,
+        EDQUOT = 1133,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 148,
+        EIDRM = 36,
+        EILSEQ = 88,
+        EINPROGRESS = 150,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 133,
+        EISDIR = 21,
+        ELOOP = 90,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 97,
+        EMULTIHOP = 74,
+        ENAMETOOLONG = 78,
+        ENETDOWN = 127,
+        ENETRESET = 129,
+        ENETUNREACH = 128,
+        ENFILE = 23,
+        ENOBUFS = 132,
+        ENODATA = 61,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 46,
+        ENOLINK = 67,
+        ENOMEM = 12,
+        ENOMSG = 35,
+        ENOPROTOOPT = 99,
+        ENOSPC = 28,
+        ENOSR = 63,
+        ENOSTR = 60,
+        ENOSYS = 89,
+        ENOTCONN = 134,
+        ENOTDIR = 20,
+        ENOTEMPTY = 93,
+        ENOTRECOVERABLE = 166,
+        ENOTSOCK = 95,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 122,
+        EOVERFLOW = 79,
+        EOWNERDEAD = 165,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 71,
+        EPROTONOSUPPORT = 120,
+        EPROTOTYPE = 98,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 151,
+        ETIME = 62,
+        ETIMEDOUT = 145,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = 56;
+}
+
+///Linux error codes on SPARC architecture.
+pub mod linux_sparc {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 48,
+        EADDRNOTAVAIL = 49,
+        EAFNOSUPPORT = 47,
+        EAGAIN = 11,
+        EALREADY = 37,
+        EBADF = 9,
+        EBADMSG = 76,
+        EBUSY = 16,
+        ECANCELED = 127,
+        ECHILD = 10,
+        ECONNABORTED = 53,
+        ECONNREFUSED = 61,
+        ECONNRESET = 54,
+        EDEADLK = 78,
+        EDESTADDRREQ = 39,
+        EDOM = 33,
+        EDQUOT = 69,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 65,
+        EIDRM = 77,
+        EILSEQ = 122,
+        EINPROGRESS = 36,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 56,
+        EISDIR = 21,
+        ELOOP = 62,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 40,
+        EMULTIHOP = 87,
+        ENAMETOOLONG = 63,
+        ENETDOWN = 50,
+        ENETRESET = 52,
+        ENETUNREACH = 51,
+        ENFILE = 23,
+        ENOBUFS = 55,
+        ENODATA = 111,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 79,
+        ENOLINK = 82,
+        ENOMEM = 12,
+        ENOMSG = 75,
+        ENOPROTOOPT = 42,
+        ENOSPC = 28,
+        ENOSR = 74,
+        ENOSTR = 72,
+        ENOSYS = 90,
+        ENOTCONN = 57,
+        ENOTDIR = 20,
+        ENOTEMPTY = 66,
+        ENOTRECOVERABLE = 133,
+        ENOTSOCK = 38,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 45,
+        EOVERFLOW = 92,
+        EOWNERDEAD = 132,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 86,
+        EPROTONOSUPPORT = 43,
+        EPROTOTYPE = 41,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 70,
+        ETIME = 73,
+        ETIMEDOUT = 60,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = 108;
+}
+
+///Error codes of Apple platforms.
+pub mod apple {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 48,
+        EADDRNOTAVAIL = 49,
+        EAFNOSUPPORT = 47,
+        EAGAIN = 35,
+        EALREADY = 37,
+        EBADF = 9,
+        EBADMSG = 94,
+        EBUSY = 16,
+        ECANCELED = 89,
+        ECHILD = 10,
+        ECONNABORTED = 53,
+        ECONNREFUSED = 61,
+        ECONNRESET = 54,
+        EDEADLK = 11,
+        EDESTADDRREQ = 39,
+        EDOM = 33,
+        EDQUOT = 69,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 65,
+        EIDRM = 90,
+        EILSEQ = 92,
+        EINPROGRESS = 36,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 56,
+        EISDIR = 21,
+        ELOOP = 62,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 40,
+        EMULTIHOP = 95,
+        ENAMETOOLONG = 63,
+        ENETDOWN = 50,
+        ENETRESET = 52,
+        ENETUNREACH = 51,
+        ENFILE = 23,
+        ENOBUFS = 55,
+        ENODATA = 96,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 77,
+        ENOLINK = 97,
+        ENOMEM = 12,
+        ENOMSG = 91,
+        ENOPROTOOPT = 42,
+        ENOSPC = 28,
+        ENOSR = 98,
+        ENOSTR = 99,
+        ENOSYS = 78,
+        ENOTCONN = 57,
+        ENOTDIR = 20,
+        ENOTEMPTY = 66,
+        ENOTRECOVERABLE = 104,
+        ENOTSOCK = 38,
+        ENOTSUP = 45,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 102,
+        EOVERFLOW = 84,
+        EOWNERDEAD = 105,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 100,
+        EPROTONOSUPPORT = 43,
+        EPROTOTYPE = 41,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 70,
+        ETIME = 101,
+        ETIMEDOUT = 60,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+    }
+}
+
+///FreeBSD error codes.
+pub mod freebsd {
+    use super::c_int;
+
+    define_errno! {
+        #[unsupported(ENODATA, ENOSR, ENOSTR, ETIME)]
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 48,
+        EADDRNOTAVAIL = 49,
+        EAFNOSUPPORT = 47,
+        EAGAIN = 35,
+        EALREADY = 37,
+        EBADF = 9,
+        EBADMSG = 89,
+        EBUSY = 16,
+        ECANCELED = 85,
+        ECHILD = 10,
+        ECONNABORTED = 53,
+        ECONNREFUSED = 61,
+        ECONNRESET = 54,
+        EDEADLK = 11,
+        EDESTADDRREQ = 39,
+        EDOM = 33,
+        EDQUOT = 69,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 65,
+        EIDRM = 82,
+        EILSEQ = 86,
+        EINPROGRESS = 36,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 56,
+        EISDIR = 21,
+        ELOOP = 62,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 40,
+        EMULTIHOP = 90,
+        ENAMETOOLONG = 63,
+        ENETDOWN = 50,
+        ENETRESET = 52,
+        ENETUNREACH = 51,
+        ENFILE = 23,
+        ENOBUFS = 55,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 77,
+        ENOLINK = 91,
+        ENOMEM = 12,
+        ENOMSG = 83,
+        ENOPROTOOPT = 42,
+        ENOSPC = 28,
+        ENOSYS = 78,
+        ENOTCONN = 57,
+        ENOTDIR = 20,
+        ENOTEMPTY = 66,
+        ENOTRECOVERABLE = 95,
+        ENOTSOCK = 38,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 45,
+        EOVERFLOW = 84,
+        EOWNERDEAD = 96,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 92,
+        EPROTONOSUPPORT = 43,
+        EPROTOTYPE = 41,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 70,
+        ETIMEDOUT = 60,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+    }
+}
+
+///DragonFly BSD error codes.
+pub mod dragonfly {
+    use super::c_int;
+
+    define_errno! {
+        #[unsupported(ENODATA, ENOSR, ENOSTR, ETIME)]
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 48,
+        EADDRNOTAVAIL = 49,
+        EAFNOSUPPORT = 47,
+        EAGAIN = 35,
+        EALREADY = 37,
+        EBADF = 9,
+        EBADMSG = 89,
+        EBUSY = 16,
+        ECANCELED = 85,
+        ECHILD = 10,
+        ECONNABORTED = 53,
+        ECONNREFUSED = 61,
+        ECONNRESET = 54,
+        EDEADLK = 11,
+        EDESTADDRREQ = 39,
+        EDOM = 33,
+        EDQUOT = 69,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 65,
+        EIDRM = 82,
+        EILSEQ = 86,
+        EINPROGRESS = 36,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 56,
+        EISDIR = 21,
+        ELOOP = 62,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 40,
+        EMULTIHOP = 90,
+        ENAMETOOLONG = 63,
+        ENETDOWN = 50,
+        ENETRESET = 52,
+        ENETUNREACH = 51,
+        ENFILE = 23,
+        ENOBUFS = 55,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 77,
+        ENOLINK = 91,
+        ENOMEM = 12,
+        ENOMSG = 83,
+        ENOPROTOOPT = 42,
+        ENOSPC = 28,
+        ENOSYS = 78,
+        ENOTCONN = 57,
+        ENOTDIR = 20,
+        ENOTEMPTY = 66,
+        ENOTRECOVERABLE = 94,
+        ENOTSOCK = 38,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 45,
+        EOVERFLOW = 84,
+        EOWNERDEAD = 95,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 92,
+        EPROTONOSUPPORT = 43,
+        EPROTOTYPE = 41,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 70,
+        ETIMEDOUT = 60,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+    }
+}
+
+///OpenBSD error codes.
+pub mod openbsd {
+    use super::c_int;
+
+    define_errno! {
+        #[unsupported(EMULTIHOP, ENODATA, ENOLINK, ENOSR, ENOSTR, ETIME)]
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 48,
+        EADDRNOTAVAIL = 49,
+        EAFNOSUPPORT = 47,
+        EAGAIN = 35,
+        EALREADY = 37,
+        EBADF = 9,
+        EBADMSG = 92,
+        EBUSY = 16,
+        ECANCELED = 88,
+        ECHILD = 10,
+        ECONNABORTED = 53,
+        ECONNREFUSED = 61,
+        ECONNRESET = 54,
+        EDEADLK = 11,
+        EDESTADDRREQ = 39,
+        EDOM = 33,
+        EDQUOT = 69,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 65,
+        EIDRM = 89,
+        EILSEQ = 84,
+        EINPROGRESS = 36,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 56,
+        EISDIR = 21,
+        ELOOP = 62,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 40,
+        ENAMETOOLONG = 63,
+        ENETDOWN = 50,
+        ENETRESET = 52,
+        ENETUNREACH = 51,
+        ENFILE = 23,
+        ENOBUFS = 55,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 77,
+        ENOMEM = 12,
+        ENOMSG = 90,
+        ENOPROTOOPT = 42,
+        ENOSPC = 28,
+        ENOSYS = 78,
+        ENOTCONN = 57,
+        ENOTDIR = 20,
+        ENOTEMPTY = 66,
+        ENOTRECOVERABLE = 93,
+        ENOTSOCK = 38,
+        ENOTSUP = 91,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 45,
+        EOVERFLOW = 87,
+        EOWNERDEAD = 94,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 95,
+        EPROTONOSUPPORT = 43,
+        EPROTOTYPE = 41,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 70,
+        ETIMEDOUT = 60,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+    }
+}
+
+///NetBSD error codes.
+pub mod netbsd {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 48,
+        EADDRNOTAVAIL = 49,
+        EAFNOSUPPORT = 47,
+        EAGAIN = 35,
+        EALREADY = 37,
+        EBADF = 9,
+        EBADMSG = 88,
+        EBUSY = 16,
+        ECANCELED = 87,
+        ECHILD = 10,
+        ECONNABORTED = 53,
+        ECONNREFUSED = 61,
+        ECONNRESET = 54,
+        EDEADLK = 11,
+        EDESTADDRREQ = 39,
+        EDOM = 33,
+        EDQUOT = 69,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 65,
+        EIDRM = 82,
+        EILSEQ = 85,
+        EINPROGRESS = 36,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 56,
+        EISDIR = 21,
+        ELOOP = 62,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 40,
+        EMULTIHOP = 94,
+        ENAMETOOLONG = 63,
+        ENETDOWN = 50,
+        ENETRESET = 52,
+        ENETUNREACH = 51,
+        ENFILE = 23,
+        ENOBUFS = 55,
+        ENODATA = 89,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 77,
+        ENOLINK = 95,
+        ENOMEM = 12,
+        ENOMSG = 83,
+        ENOPROTOOPT = 42,
+        ENOSPC = 28,
+        ENOSR = 90,
+        ENOSTR = 91,
+        ENOSYS = 78,
+        ENOTCONN = 57,
+        ENOTDIR = 20,
+        ENOTEMPTY = 66,
+        ENOTRECOVERABLE = 98,
+        ENOTSOCK = 38,
+        ENOTSUP = 86,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 45,
+        EOVERFLOW = 84,
+        EOWNERDEAD = 97,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 96,
+        EPROTONOSUPPORT = 43,
+        EPROTOTYPE = 41,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 70,
+        ETIME = 92,
+        ETIMEDOUT = 60,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+    }
+}
+
+///Error codes of Solaris and illumos.
+pub mod solarish {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 125,
+        EADDRNOTAVAIL = 126,
+        EAFNOSUPPORT = 124,
+        EAGAIN = 11,
+        EALREADY = 149,
+        EBADF = 9,
+        EBADMSG = 77,
+        EBUSY = 16,
+        ECANCELED = 47,
+        ECHILD = 10,
+        ECONNABORTED = 130,
+        ECONNREFUSED = 146,
+        ECONNRESET = 131,
+        EDEADLK = 45,
+        EDESTADDRREQ = 96,
+        EDOM = 33,
+        EDQUOT = 49,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 148,
+        EIDRM = 36,
+        EILSEQ = 88,
+        EINPROGRESS = 150,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 133,
+        EISDIR = 21,
+        ELOOP = 90,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 97,
+        EMULTIHOP = 74,
+        ENAMETOOLONG = 78,
+        ENETDOWN = 127,
+        ENETRESET = 129,
+        ENETUNREACH = 128,
+        ENFILE = 23,
+        ENOBUFS = 132,
+        ENODATA = 61,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 46,
+        ENOLINK = 67,
+        ENOMEM = 12,
+        ENOMSG = 35,
+        ENOPROTOOPT = 99,
+        ENOSPC = 28,
+        ENOSR = 63,
+        ENOSTR = 60,
+        ENOSYS = 89,
+        ENOTCONN = 134,
+        ENOTDIR = 20,
+        ENOTEMPTY = 93,
+        ENOTRECOVERABLE = 59,
+        ENOTSOCK = 95,
+        ENOTSUP = 48,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 122,
+        EOVERFLOW = 79,
+        EOWNERDEAD = 58,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 71,
+        EPROTONOSUPPORT = 120,
+        EPROTOTYPE = 98,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 151,
+        ETIME = 62,
+        ETIMEDOUT = 145,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = 56;
+}
+
+///AIX error codes.
+pub mod aix {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 67,
+        EADDRNOTAVAIL = 68,
+        EAFNOSUPPORT = 66,
+        EAGAIN = 11,
+        EALREADY = 56,
+        EBADF = 9,
+        EBADMSG = 120,
+        EBUSY = 16,
+        ECANCELED = 117,
+        ECHILD = 10,
+        ECONNABORTED = 72,
+        ECONNREFUSED = 79,
+        ECONNRESET = 73,
+        EDEADLK = 45,
+        EDESTADDRREQ = 58,
+        EDOM = 33,
+        EDQUOT = 88,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 81,
+        EIDRM = 36,
+        EILSEQ = 116,
+        EINPROGRESS = 55,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 75,
+        EISDIR = 21,
+        ELOOP = 85,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 59,
+        EMULTIHOP = 125,
+        ENAMETOOLONG = 86,
+        ENETDOWN = 69,
+        ENETRESET = 71,
+        ENETUNREACH = 70,
+        ENFILE = 23,
+        ENOBUFS = 74,
+        ENODATA = 122,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 49,
+        ENOLINK = 126,
+        ENOMEM = 12,
+        ENOMSG = 35,
+        ENOPROTOOPT = 61,
+        ENOSPC = 28,
+        ENOSR = 118,
+        ENOSTR = 123,
+        ENOSYS = 109,
+        ENOTCONN = 76,
+        ENOTDIR = 20,
+        ENOTEMPTY = 87,
+        ENOTRECOVERABLE = 94,
+        ENOTSOCK = 57,
+        ENOTSUP = 124,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 64,
+        EOVERFLOW = 127,
+        EOWNERDEAD = 95,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 121,
+        EPROTONOSUPPORT = 62,
+        EPROTOTYPE = 60,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 52,
+        ETIME = 119,
+        ETIMEDOUT = 78,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+    }
+}
+
+///QNX Neutrino error codes.
+pub mod nto {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 248,
+        EADDRNOTAVAIL = 249,
+        EAFNOSUPPORT = 247,
+        EAGAIN = 11,
+        EALREADY = 237,
+        EBADF = 9,
+        EBADMSG = 77,
+        EBUSY = 16,
+        ECANCELED = 47,
+        ECHILD = 10,
+        ECONNABORTED = 253,
+        ECONNREFUSED = 261,
+        ECONNRESET = 254,
+        EDEADLK = 45,
+        EDESTADDRREQ = 239,
+        EDOM = 33,
+        EDQUOT = 49,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 265,
+        EIDRM = 36,
+        EILSEQ = 88,
+        EINPROGRESS = 236,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 256,
+        EISDIR = 21,
+        ELOOP = 90,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 240,
+        EMULTIHOP = 74,
+        ENAMETOOLONG = 78,
+        ENETDOWN = 250,
+        ENETRESET = 252,
+        ENETUNREACH = 251,
+        ENFILE = 23,
+        ENOBUFS = 255,
+        ENODATA = 61,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 46,
+        ENOLINK = 67,
+        ENOMEM = 12,
+        ENOMSG = 35,
+        ENOPROTOOPT = 242,
+        ENOSPC = 28,
+        ENOSR = 63,
+        ENOSTR = 60,
+        ENOSYS = 89,
+        ENOTCONN = 257,
+        ENOTDIR = 20,
+        ENOTEMPTY = 93,
+        ENOTRECOVERABLE = 95,
+        ENOTSOCK = 238,
+        ENOTSUP = 48,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 103,
+        EOVERFLOW = 79,
+        EOWNERDEAD = 58,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 71,
+        EPROTONOSUPPORT = 243,
+        EPROTOTYPE = 241,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 122,
+        ETIME = 62,
+        ETIMEDOUT = 260,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = 56;
+}
+
+///Error codes of newlib based environments.
+pub mod newlib {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 112,
+        EADDRNOTAVAIL = 125,
+        EAFNOSUPPORT = 106,
+        EAGAIN = 11,
+        EALREADY = 120,
+        EBADF = 9,
+        EBADMSG = 77,
+        EBUSY = 16,
+        ECANCELED = 140,
+        ECHILD = 10,
+        ECONNABORTED = 113,
+        ECONNREFUSED = 111,
+        ECONNRESET = 104,
+        EDEADLK = 45,
+        EDESTADDRREQ = 121,
+        EDOM = 33,
+        EDQUOT = 132,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 118,
+        EIDRM = 36,
+        EILSEQ = 138,
+        EINPROGRESS = 119,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 127,
+        EISDIR = 21,
+        ELOOP = 92,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 122,
+        EMULTIHOP = 74,
+        ENAMETOOLONG = 91,
+        ENETDOWN = 115,
+        ENETRESET = 126,
+        ENETUNREACH = 114,
+        ENFILE = 23,
+        ENOBUFS = 105,
+        ENODATA = 61,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 46,
+        ENOLINK = 67,
+        ENOMEM = 12,
+        ENOMSG = 35,
+        ENOPROTOOPT = 109,
+        ENOSPC = 28,
+        ENOSR = 63,
+        ENOSTR = 60,
+        ENOSYS = 88,
+        ENOTCONN = 128,
+        ENOTDIR = 20,
+        ENOTEMPTY = 90,
+        ENOTRECOVERABLE = 141,
+        ENOTSOCK = 108,
+        ENOTSUP = 134,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 95,
+        EOVERFLOW = 139,
+        EOWNERDEAD = 142,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 71,
+        EPROTONOSUPPORT = 123,
+        EPROTOTYPE = 107,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 133,
+        ETIME = 62,
+        ETIMEDOUT = 116,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+    }
+}
+
+///Haiku error codes.
+///
+///Haiku shares error codes between POSIX and native API, hence all of them are negative.
+///
+///Not available on targets with 16-bit `c_int`, which cannot represent these codes.
+#[cfg(not(any(target_arch = "avr", target_arch = "msp430")))]
+pub mod haiku {
+    use super::c_int;
+
+    define_errno! {
+        #[unsupported(ENOTRECOVERABLE, EOWNERDEAD)]
+        E2BIG = -2147454975,
+        EACCES = -2147483646,
+        EADDRINUSE = -2147454954,
+        EADDRNOTAVAIL = -2147454953,
+        EAFNOSUPPORT = -2147454955,
+        EAGAIN = -2147483637,
+        EALREADY = -2147454939,
+        EBADF = -2147459072,
+        EBADMSG = -2147454930,
+        EBUSY = -2147483634,
+        ECANCELED = -2147454929,
+        ECHILD = -2147454974,
+        ECONNABORTED = -2147454949,
+        ECONNREFUSED = -2147454944,
+        ECONNRESET = -2147454948,
+        EDEADLK = -2147454973,
+        EDESTADDRREQ = -2147454928,
+        EDOM = -2147454960,
+        EDQUOT = -2147454927,
+        EEXIST = -2147459070,
+        EFAULT = -2147478783,
+        EFBIG = -2147454972,
+        EHOSTUNREACH = -2147454943,
+        EIDRM = -2147454926,
+        EILSEQ = -2147454938,
+        EINPROGRESS = -2147454940,
+        EINTR = -2147483638,
+        EINVAL = -2147483643,
+        EIO = -2147483647,
+        EISCONN = -2147454947,
+        EISDIR = -2147459063,
+        ELOOP = -2147459060,
+        EMFILE = -2147459062,
+        EMLINK = -2147454971,
+        EMSGSIZE = -2147454934,
+        EMULTIHOP = -2147454925,
+        ENAMETOOLONG = -2147459068,
+        ENETDOWN = -2147454952,
+        ENETRESET = -2147454950,
+        ENETUNREACH = -2147454951,
+        ENFILE = -2147454970,
+        ENOBUFS = -2147454941,
+        ENODATA = -2147454924,
+        ENODEV = -2147454969,
+        ENOENT = -2147459069,
+        ENOEXEC = -2147478782,
+        ENOLCK = -2147454968,
+        ENOLINK = -2147454923,
+        ENOMEM = -2147483648,
+        ENOMSG = -2147454937,
+        ENOPROTOOPT = -2147454942,
+        ENOSPC = -2147459065,
+        ENOSR = -2147454922,
+        ENOSTR = -2147454921,
+        ENOSYS = -2147454967,
+        ENOTCONN = -2147454946,
+        ENOTDIR = -2147459067,
+        ENOTEMPTY = -2147459066,
+        ENOTSOCK = -2147454932,
+        ENOTSUP = -2147454920,
+        ENOTTY = -2147454966,
+        ENXIO = -2147454965,
+        EOPNOTSUPP = -2147454933,
+        EOVERFLOW = -2147454935,
+        EPERM = -2147483633,
+        EPIPE = -2147459059,
+        EPROTO = -2147454919,
+        EPROTONOSUPPORT = -2147454957,
+        EPROTOTYPE = -2147454958,
+        ERANGE = -2147454959,
+        EROFS = -2147459064,
+        ESPIPE = -2147454964,
+        ESRCH = -2147454963,
+        ESTALE = -2147454936,
+        ETIME = -2147454918,
+        ETIMEDOUT = -2147483639,
+        ETXTBSY = -2147454917,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = -2147459061,
+    }
+}
+
+///Redox error codes.
+pub mod redox {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 98,
+        EADDRNOTAVAIL = 99,
+        EAFNOSUPPORT = 97,
+        EAGAIN = 11,
+        EALREADY = 114,
+        EBADF = 9,
+        EBADMSG = 74,
+        EBUSY = 16,
+        ECANCELED = 125,
+        ECHILD = 10,
+        ECONNABORTED = 103,
+        ECONNREFUSED = 111,
+        ECONNRESET = 104,
+        EDEADLK = 35,
+        EDESTADDRREQ = 89,
+        EDOM = 33,
+        EDQUOT = 122,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 113,
+        EIDRM = 43,
+        EILSEQ = 84,
+        EINPROGRESS = 115,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 106,
+        EISDIR = 21,
+        ELOOP = 40,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 90,
+        EMULTIHOP = 72,
+        ENAMETOOLONG = 36,
+        ENETDOWN = 100,
+        ENETRESET = 102,
+        ENETUNREACH = 101,
+        ENFILE = 23,
+        ENOBUFS = 105,
+        ENODATA = 61,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 37,
+        ENOLINK = 67,
+        ENOMEM = 12,
+        ENOMSG = 42,
+        ENOPROTOOPT = 92,
+        ENOSPC = 28,
+        ENOSR = 63,
+        ENOSTR = 60,
+        ENOSYS = 38,
+        ENOTCONN = 107,
+        ENOTDIR = 20,
+        ENOTEMPTY = 39,
+        ENOTRECOVERABLE = 131,
+        ENOTSOCK = 88,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 95,
+        EOVERFLOW = 75,
+        EOWNERDEAD = 130,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 71,
+        EPROTONOSUPPORT = 93,
+        EPROTOTYPE = 91,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 116,
+        ETIME = 62,
+        ETIMEDOUT = 110,
+        ETXTBSY = 26,
+        EWOULDBLOCK = 41,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = 58;
+}
+
+///WASI error codes.
+pub mod wasi {
+    use super::c_int;
+
+    define_errno! {
+        #[unsupported(ENODATA, ENOSR, ENOSTR, ETIME)]
+        E2BIG = 1,
+        EACCES = 2,
+        EADDRINUSE = 3,
+        EADDRNOTAVAIL = 4,
+        EAFNOSUPPORT = 5,
+        EAGAIN = 6,
+        EALREADY = 7,
+        EBADF = 8,
+        EBADMSG = 9,
+        EBUSY = 10,
+        ECANCELED = 11,
+        ECHILD = 12,
+        ECONNABORTED = 13,
+        ECONNREFUSED = 14,
+        ECONNRESET = 15,
+        EDEADLK = 16,
+        EDESTADDRREQ = 17,
+        EDOM = 18,
+        EDQUOT = 19,
+        EEXIST = 20,
+        EFAULT = 21,
+        EFBIG = 22,
+        EHOSTUNREACH = 23,
+        EIDRM = 24,
+        EILSEQ = 25,
+        EINPROGRESS = 26,
+        EINTR = 27,
+        EINVAL = 28,
+        EIO = 29,
+        EISCONN = 30,
+        EISDIR = 31,
+        ELOOP = 32,
+        EMFILE = 33,
+        EMLINK = 34,
+        EMSGSIZE = 35,
+        EMULTIHOP = 36,
+        ENAMETOOLONG = 37,
+        ENETDOWN = 38,
+        ENETRESET = 39,
+        ENETUNREACH = 40,
+        ENFILE = 41,
+        ENOBUFS = 42,
+        ENODEV = 43,
+        ENOENT = 44,
+        ENOEXEC = 45,
+        ENOLCK = 46,
+        ENOLINK = 47,
+        ENOMEM = 48,
+        ENOMSG = 49,
+        ENOPROTOOPT = 50,
+        ENOSPC = 51,
+        ENOSYS = 52,
+        ENOTCONN = 53,
+        ENOTDIR = 54,
+        ENOTEMPTY = 55,
+        ENOTRECOVERABLE = 56,
+        ENOTSOCK = 57,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 59,
+        ENXIO = 60,
+        EOPNOTSUPP = 58,
+        EOVERFLOW = 61,
+        EOWNERDEAD = 62,
+        EPERM = 63,
+        EPIPE = 64,
+        EPROTO = 65,
+        EPROTONOSUPPORT = 66,
+        EPROTOTYPE = 67,
+        ERANGE = 68,
+        EROFS = 69,
+        ESPIPE = 70,
+        ESRCH = 71,
+        ESTALE = 72,
+        ETIMEDOUT = 73,
+        ETXTBSY = 74,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 75,
+    }
+}
+
+///Emscripten error codes.
+///
+///Same as WASI, except for `ENOTSUP` and `EOPNOTSUPP`, and STREAMS error codes (e.g. `ENOSTR`), which are
+///not supported by WASI.
+pub mod emscripten {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 1,
+        EACCES = 2,
+        EADDRINUSE = 3,
+        EADDRNOTAVAIL = 4,
+        EAFNOSUPPORT = 5,
+        EAGAIN = 6,
+        EALREADY = 7,
+        EBADF = 8,
+        EBADMSG = 9,
+        EBUSY = 10,
+        ECANCELED = 11,
+        ECHILD = 12,
+        ECONNABORTED = 13,
+        ECONNREFUSED = 14,
+        ECONNRESET = 15,
+        EDEADLK = 16,
+        EDESTADDRREQ = 17,
+        EDOM = 18,
+        EDQUOT = 19,
+        EEXIST = 20,
+        EFAULT = 21,
+        EFBIG = 22,
+        EHOSTUNREACH = 23,
+        EIDRM = 24,
+        EILSEQ = 25,
+        EINPROGRESS = 26,
+        EINTR = 27,
+        EINVAL = 28,
+        EIO = 29,
+        EISCONN = 30,
+        EISDIR = 31,
+        ELOOP = 32,
+        EMFILE = 33,
+        EMLINK = 34,
+        EMSGSIZE = 35,
+        EMULTIHOP = 36,
+        ENAMETOOLONG = 37,
+        ENETDOWN = 38,
+        ENETRESET = 39,
+        ENETUNREACH = 40,
+        ENFILE = 41,
+        ENOBUFS = 42,
+        ENODATA = 116,
+        ENODEV = 43,
+        ENOENT = 44,
+        ENOEXEC = 45,
+        ENOLCK = 46,
+        ENOLINK = 47,
+        ENOMEM = 48,
+        ENOMSG = 49,
+        ENOPROTOOPT = 50,
+        ENOSPC = 51,
+        ENOSR = 118,
+        ENOSTR = 100,
+        ENOSYS = 52,
+        ENOTCONN = 53,
+        ENOTDIR = 54,
+        ENOTEMPTY = 55,
+        ENOTRECOVERABLE = 56,
+        ENOTSOCK = 57,
+        ENOTSUP = EOPNOTSUPP,
+        ENOTTY = 59,
+        ENXIO = 60,
+        EOPNOTSUPP = 138,
+        EOVERFLOW = 61,
+        EOWNERDEAD = 62,
+        EPERM = 63,
+        EPIPE = 64,
+        EPROTO = 65,
+        EPROTONOSUPPORT = 66,
+        EPROTOTYPE = 67,
+        ERANGE = 68,
+        EROFS = 69,
+        ESPIPE = 70,
+        ESRCH = 71,
+        ESTALE = 72,
+        ETIME = 117,
+        ETIMEDOUT = 73,
+        ETXTBSY = 74,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 75,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = EDEADLK;
+}
+
+///Error codes of Windows C runtime.
+pub mod windows {
+    use super::c_int;
+
+    define_errno! {
+        #[unsupported(EDQUOT, EMULTIHOP, ESTALE)]
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 100,
+        EADDRNOTAVAIL = 101,
+        EAFNOSUPPORT = 102,
+        EAGAIN = 11,
+        EALREADY = 103,
+        EBADF = 9,
+        EBADMSG = 104,
+        EBUSY = 16,
+        ECANCELED = 105,
+        ECHILD = 10,
+        ECONNABORTED = 106,
+        ECONNREFUSED = 107,
+        ECONNRESET = 108,
+        EDEADLK = 36,
+        EDESTADDRREQ = 109,
+        EDOM = 33,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 110,
+        EIDRM = 111,
+        EILSEQ = 42,
+        EINPROGRESS = 112,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 113,
+        EISDIR = 21,
+        ELOOP = 114,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 115,
+        ENAMETOOLONG = 38,
+        ENETDOWN = 116,
+        ENETRESET = 117,
+        ENETUNREACH = 118,
+        ENFILE = 23,
+        ENOBUFS = 119,
+        ENODATA = 120,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 39,
+        ENOLINK = 121,
+        ENOMEM = 12,
+        ENOMSG = 122,
+        ENOPROTOOPT = 123,
+        ENOSPC = 28,
+        ENOSR = 124,
+        ENOSTR = 125,
+        ENOSYS = 40,
+        ENOTCONN = 126,
+        ENOTDIR = 20,
+        ENOTEMPTY = 41,
+        ENOTRECOVERABLE = 127,
+        ENOTSOCK = 128,
+        ENOTSUP = 129,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 130,
+        EOVERFLOW = 132,
+        EOWNERDEAD = 133,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 134,
+        EPROTONOSUPPORT = 135,
+        EPROTOTYPE = 136,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ETIME = 137,
+        ETIMEDOUT = 138,
+        ETXTBSY = 139,
+        EWOULDBLOCK = 140,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = EDEADLK;
+}
+
+///VxWorks error codes.
+pub mod vxworks {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 48,
+        EADDRNOTAVAIL = 49,
+        EAFNOSUPPORT = 47,
+        EAGAIN = 11,
+        EALREADY = 69,
+        EBADF = 9,
+        EBADMSG = 77,
+        EBUSY = 16,
+        ECANCELED = 72,
+        ECHILD = 10,
+        ECONNABORTED = 53,
+        ECONNREFUSED = 61,
+        ECONNRESET = 54,
+        EDEADLK = 33,
+        EDESTADDRREQ = 40,
+        EDOM = 37,
+        EDQUOT = 83,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 65,
+        EIDRM = 84,
+        EILSEQ = 82,
+        EINPROGRESS = 68,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 56,
+        EISDIR = 21,
+        ELOOP = 64,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 36,
+        EMULTIHOP = 86,
+        ENAMETOOLONG = 26,
+        ENETDOWN = 62,
+        ENETRESET = 52,
+        ENETUNREACH = 51,
+        ENFILE = 23,
+        ENOBUFS = 55,
+        ENODATA = 78,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 34,
+        ENOLINK = 87,
+        ENOMEM = 12,
+        ENOMSG = 80,
+        ENOPROTOOPT = 42,
+        ENOSPC = 28,
+        ENOSR = 74,
+        ENOSTR = 75,
+        ENOSYS = 71,
+        ENOTCONN = 57,
+        ENOTDIR = 20,
+        ENOTEMPTY = 15,
+        ENOTRECOVERABLE = 90,
+        ENOTSOCK = 50,
+        ENOTSUP = 35,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 45,
+        EOVERFLOW = 85,
+        EOWNERDEAD = 89,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 76,
+        EPROTONOSUPPORT = 43,
+        EPROTOTYPE = 41,
+        ERANGE = 38,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 88,
+        ETIME = 79,
+        ETIMEDOUT = 60,
+        ETXTBSY = 63,
+        EWOULDBLOCK = 70,
+        EXDEV = 18,
+    }
+}
+
+///SOLID error codes.
+pub mod solid {
+    use super::c_int;
+
+    define_errno! {
+        E2BIG = 7,
+        EACCES = 13,
+        EADDRINUSE = 98,
+        EADDRNOTAVAIL = 99,
+        EAFNOSUPPORT = 97,
+        EAGAIN = 11,
+        EALREADY = 114,
+        EBADF = 9,
+        EBADMSG = 74,
+        EBUSY = 16,
+        ECANCELED = 125,
+        ECHILD = 10,
+        ECONNABORTED = 103,
+        ECONNREFUSED = 111,
+        ECONNRESET = 104,
+        EDEADLK = 35,
+        EDESTADDRREQ = 89,
+        EDOM = 33,
+        EDQUOT = 122,
+        EEXIST = 17,
+        EFAULT = 14,
+        EFBIG = 27,
+        EHOSTUNREACH = 113,
+        EIDRM = 43,
+        EILSEQ = 84,
+        EINPROGRESS = 115,
+        EINTR = 4,
+        EINVAL = 22,
+        EIO = 5,
+        EISCONN = 106,
+        EISDIR = 21,
+        ELOOP = 40,
+        EMFILE = 24,
+        EMLINK = 31,
+        EMSGSIZE = 90,
+        EMULTIHOP = 72,
+        ENAMETOOLONG = 36,
+        ENETDOWN = 100,
+        ENETRESET = 102,
+        ENETUNREACH = 101,
+        ENFILE = 23,
+        ENOBUFS = 105,
+        ENODATA = 61,
+        ENODEV = 19,
+        ENOENT = 2,
+        ENOEXEC = 8,
+        ENOLCK = 37,
+        ENOLINK = 67,
+        ENOMEM = 12,
+        ENOMSG = 42,
+        ENOPROTOOPT = 92,
+        ENOSPC = 28,
+        ENOSR = 63,
+        ENOSTR = 60,
+        ENOSYS = 38,
+        ENOTCONN = 107,
+        ENOTDIR = 20,
+        ENOTEMPTY = 39,
+        ENOTRECOVERABLE = 131,
+        ENOTSOCK = 88,
+        ENOTSUP = 132,
+        ENOTTY = 25,
+        ENXIO = 6,
+        EOPNOTSUPP = 95,
+        EOVERFLOW = 75,
+        EOWNERDEAD = 130,
+        EPERM = 1,
+        EPIPE = 32,
+        EPROTO = 71,
+        EPROTONOSUPPORT = 93,
+        EPROTOTYPE = 91,
+        ERANGE = 34,
+        EROFS = 30,
+        ESPIPE = 29,
+        ESRCH = 3,
+        ESTALE = 116,
+        ETIME = 62,
+        ETIMEDOUT = 110,
+        ETXTBSY = 26,
+        EWOULDBLOCK = EAGAIN,
+        EXDEV = 18,
+        ;
+        EDEADLOCK => EDEADLK,
+    }
+
+    ///EDEADLOCK
+    pub const EDEADLOCK: c_int = EDEADLK;
+}
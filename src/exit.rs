@@ -0,0 +1,116 @@
+//!Exit code category
+//!
+//!Describes exit codes of processes, including conventional codes of `sysexits.h`.
+use crate::{Category, ErrorCode, MessageBuf};
+use crate::utils::write_message_buf;
+use crate::types::c_int;
+
+use core::{fmt, ptr};
+
+///Exit code category, describing non-zero exit codes of processes.
+///
+///Codes of `sysexits.h` are described using built-in table, while the rest are considered
+///generic failure.
+///
+///## Usage
+///
+///```rust
+///use error_code::{ErrorCode, EXIT_CATEGORY};
+///use error_code::exit;
+///
+///let error = ErrorCode::new(exit::EX_USAGE, &EXIT_CATEGORY);
+///assert_eq!(exit::name(error.raw_code()), Some("EX_USAGE"));
+///assert_eq!(error.to_string(), "ExitCode(64): Command line usage error");
+///assert_eq!(ErrorCode::new(3, &EXIT_CATEGORY).message(), "Process exited with failure status");
+///```
+pub static EXIT_CATEGORY: Category = Category {
+    name: "ExitCode",
+    message,
+    equivalent,
+    is_would_block,
+    write_message: Some(write_message),
+};
+
+macro_rules! define_exit {
+    ($($name:ident = $code:literal => $message:literal,)+) => {
+        $(
+            #[doc = $message]
+            pub const $name: c_int = $code;
+        )+
+
+        const TABLE: &[(c_int, &str, &str)] = &[
+            $(
+                ($code, stringify!($name), $message),
+            )+
+        ];
+    };
+}
+
+define_exit! {
+    EX_OK = 0 => "Successful termination",
+    EX_USAGE = 64 => "Command line usage error",
+    EX_DATAERR = 65 => "Data format error",
+    EX_NOINPUT = 66 => "Cannot open input",
+    EX_NOUSER = 67 => "Addressee unknown",
+    EX_NOHOST = 68 => "Host name unknown",
+    EX_UNAVAILABLE = 69 => "Service unavailable",
+    EX_SOFTWARE = 70 => "Internal software error",
+    EX_OSERR = 71 => "System error",
+    EX_OSFILE = 72 => "Critical OS file missing",
+    EX_CANTCREAT = 73 => "Cannot create output file",
+    EX_IOERR = 74 => "Input/output error",
+    EX_TEMPFAIL = 75 => "Temporary failure, retry later",
+    EX_PROTOCOL = 76 => "Remote error in protocol",
+    EX_NOPERM = 77 => "Permission denied",
+    EX_CONFIG = 78 => "Configuration error",
+}
+
+///Code used by [ErrorCode::from_exit_status](../struct.ErrorCode.html#method.from_exit_status) when
+///process has no exit code, because it neither exited nor was terminated by signal (e.g. it is stopped).
+pub const NOT_EXITED: c_int = -1;
+
+///Text to describe exit code, that has no conventional meaning.
+const GENERIC_FAILURE: &str = "Process exited with failure status";
+///Text to describe [NOT_EXITED](constant.NOT_EXITED.html).
+const NOT_EXITED_MESSAGE: &str = "Process has not exited";
+
+#[inline]
+fn lookup(code: c_int) -> Option<&'static (c_int, &'static str, &'static str)> {
+    TABLE.iter().find(|entry| entry.0 == code)
+}
+
+#[inline]
+///Returns symbolic name of exit code, if it is defined by `sysexits.h`.
+pub fn name(code: c_int) -> Option<&'static str> {
+    lookup(code).map(|entry| entry.1)
+}
+
+#[inline]
+///Returns description of exit code, if it is defined by `sysexits.h`.
+pub fn description(code: c_int) -> Option<&'static str> {
+    lookup(code).map(|entry| entry.2)
+}
+
+fn equivalent(code: c_int, other: &ErrorCode) -> bool {
+    ptr::eq(&EXIT_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+//Exit status describes finished process, hence it is never WouldBlock like error
+fn is_would_block(_: c_int) -> bool {
+    false
+}
+
+fn text(code: c_int) -> &'static str {
+    match code {
+        NOT_EXITED => NOT_EXITED_MESSAGE,
+        code => description(code).unwrap_or(GENERIC_FAILURE),
+    }
+}
+
+fn message(code: c_int, out: &mut MessageBuf) -> &str {
+    write_message_buf(out, text(code))
+}
+
+fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    out.write_str(text(code))
+}
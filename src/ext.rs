@@ -0,0 +1,169 @@
+use crate::ErrorCode;
+use crate::posix::{self, Errno};
+
+use core::fmt;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+///Error code with context, describing failed operation.
+pub struct ContextError {
+    context: &'static str,
+    code: ErrorCode,
+}
+
+impl ContextError {
+    #[inline(always)]
+    ///Creates new error with `context`.
+    pub const fn new(context: &'static str, code: ErrorCode) -> Self {
+        Self {
+            context,
+            code,
+        }
+    }
+
+    #[inline(always)]
+    ///Gets context of the error.
+    pub const fn context(&self) -> &'static str {
+        self.context
+    }
+
+    #[inline(always)]
+    ///Gets underlying error code.
+    pub const fn code(&self) -> ErrorCode {
+        self.code
+    }
+}
+
+impl From<ContextError> for ErrorCode {
+    #[inline(always)]
+    fn from(error: ContextError) -> Self {
+        error.code
+    }
+}
+
+impl fmt::Debug for ContextError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ContextError").field("context", &self.context).field("code", &self.code).finish()
+    }
+}
+
+impl fmt::Display for ContextError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("{}: {}", self.context, self.code))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ContextError {}
+
+///Extension methods for results with error codes.
+///
+///Codes are matched only against errors of `POSIX` category, or `SYSTEM` category on non-Windows
+///platforms, considering aliases (e.g. `EWOULDBLOCK` and `EAGAIN`) equal.
+///On Windows, system errors of `std::io::Error` are matched using [win32::try_to_errno](win32/fn.try_to_errno.html).
+///
+///## Usage
+///
+///```rust
+///use error_code::{defs, ErrorCode, ResultExt};
+///use error_code::posix::Errno;
+///
+///fn create_dir() -> Result<(), ErrorCode> {
+///    Err(ErrorCode::new_posix(defs::EAGAIN))
+///}
+///
+///assert_eq!(create_dir().ignore_code(Errno::EAGAIN), Ok(()));
+///assert_eq!(create_dir().map_would_block_to_none(), Ok(None));
+///
+///let error = create_dir().context("Creating cache").unwrap_err();
+///assert_eq!(error.to_string(), format!("Creating cache: {}", ErrorCode::new_posix(defs::EAGAIN)));
+///```
+pub trait ResultExt<T>: Sized {
+    ///Error type
+    type Error;
+
+    ///Turns error with specified `code` into `Ok(T::default())`.
+    fn ignore_code(self, code: Errno) -> Result<T, Self::Error> where T: Default;
+
+    ///Attaches `context` to the error.
+    fn context(self, context: &'static str) -> Result<T, ContextError>;
+
+    ///Turns WouldBlock like error into `Ok(None)`.
+    fn map_would_block_to_none(self) -> Result<Option<T>, Self::Error>;
+
+    ///Calls `retry` as long as result is error with specified `code`.
+    ///
+    ///There is no limit on number of attempts: it loops until `retry` returns different result,
+    ///hence it is intended for transient errors like `EINTR`.
+    fn or_retry_on<F: FnMut() -> Self>(self, code: Errno, retry: F) -> Self;
+}
+
+macro_rules! impl_result_ext {
+    ($error:ty, $is_code:expr, $is_would_block:expr, $into_error_code:expr) => {
+        impl<T> ResultExt<T> for Result<T, $error> {
+            type Error = $error;
+
+            #[inline]
+            fn ignore_code(self, code: Errno) -> Result<T, Self::Error> where T: Default {
+                match self {
+                    Err(error) if $is_code(&error, code) => Ok(T::default()),
+                    result => result,
+                }
+            }
+
+            #[inline]
+            fn context(self, context: &'static str) -> Result<T, ContextError> {
+                self.map_err(|error| ContextError::new(context, $into_error_code(error)))
+            }
+
+            #[inline]
+            fn map_would_block_to_none(self) -> Result<Option<T>, Self::Error> {
+                match self {
+                    Ok(result) => Ok(Some(result)),
+                    Err(error) if $is_would_block(&error) => Ok(None),
+                    Err(error) => Err(error),
+                }
+            }
+
+            #[inline]
+            fn or_retry_on<F: FnMut() -> Self>(mut self, code: Errno, mut retry: F) -> Self {
+                loop {
+                    match self {
+                        Err(ref error) if $is_code(error, code) => self = retry(),
+                        result => break result,
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[inline]
+fn is_errno(code: Option<Errno>, expected: Errno) -> bool {
+    match code {
+        Some(code) => posix::normalize(code.raw()) == posix::normalize(expected.raw()),
+        None => false,
+    }
+}
+
+#[inline]
+fn is_error_code(error: &ErrorCode, code: Errno) -> bool {
+    is_errno(error.as_errno(), code)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+fn is_io_error_code(error: &std::io::Error, code: Errno) -> bool {
+    match error.raw_os_error() {
+        #[cfg(windows)]
+        Some(raw) => is_errno(crate::win32::try_to_errno(raw), code),
+        #[cfg(not(windows))]
+        Some(raw) => is_errno(Errno::from_raw(raw), code),
+        None => false,
+    }
+}
+
+impl_result_ext!(ErrorCode, is_error_code, ErrorCode::is_would_block, core::convert::identity);
+#[cfg(feature = "std")]
+impl_result_ext!(std::io::Error, is_io_error_code, |error: &std::io::Error| error.kind() == std::io::ErrorKind::WouldBlock, ErrorCode::from);
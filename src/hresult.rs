@@ -0,0 +1,337 @@
+//!HRESULT error category
+//!
+//!Describes COM error codes using built-in tables, hence it is available on every platform.
+use crate::{Category, ErrorCode, MessageBuf, WIN32_CATEGORY};
+use crate::utils::{MessageWriter, generic_map_error_code};
+use crate::types::c_int;
+
+use core::{fmt, ptr};
+
+///HRESULT error category, describing COM error codes on any platform.
+///
+///Message includes hexadecimal representation of the code and its facility.
+///
+///## Usage
+///
+///```rust
+///use error_code::ErrorCode;
+///use error_code::hresult::{self, HResult, HRESULT_CATEGORY};
+///
+///let error = ErrorCode::new(hresult::E_ACCESSDENIED, &HRESULT_CATEGORY);
+///assert_eq!(error.message(), "0x80070005 FACILITY_WIN32: General access denied error");
+///
+///let error = HResult::from_win32(2);
+///assert_eq!(error.to_string(), "0x80070002");
+///assert_eq!(error.to_win32(), Some(ErrorCode::new(2, &error_code::WIN32_CATEGORY)));
+///```
+pub static HRESULT_CATEGORY: Category = Category {
+    name: "HResult",
+    message,
+    equivalent,
+    is_would_block,
+    write_message: Some(write_message),
+};
+
+macro_rules! define_hresult {
+    ($($name:ident = $code:literal => $message:literal,)+) => {
+        $(
+            #[doc = $message]
+            pub const $name: c_int = $code as u32 as c_int;
+        )+
+
+        const TABLE: &[(c_int, &str, &str)] = &[
+            $(
+                ($name, stringify!($name), $message),
+            )+
+        ];
+    };
+}
+
+define_hresult! {
+    S_OK = 0x00000000 => "Operation successful",
+    S_FALSE = 0x00000001 => "Operation successful, but returned false",
+    E_PENDING = 0x8000000A => "The data necessary to complete this operation is not yet available",
+    E_BOUNDS = 0x8000000B => "The operation attempted to access data outside the valid range",
+    E_CHANGED_STATE = 0x8000000C => "A concurrent or interleaved operation changed the state of the object, invalidating this operation",
+    E_ILLEGAL_STATE_CHANGE = 0x8000000D => "An illegal state change was requested",
+    E_ILLEGAL_METHOD_CALL = 0x8000000E => "A method was called at an unexpected time",
+    E_UNEXPECTED = 0x8000FFFF => "Unexpected failure",
+    E_NOTIMPL = 0x80004001 => "Not implemented",
+    E_NOINTERFACE = 0x80004002 => "No such interface supported",
+    E_POINTER = 0x80004003 => "Pointer that is not valid",
+    E_ABORT = 0x80004004 => "Operation aborted",
+    E_FAIL = 0x80004005 => "Unspecified failure",
+    RPC_E_SERVERFAULT = 0x80010105 => "The server threw an exception",
+    RPC_E_CHANGED_MODE = 0x80010106 => "Cannot change thread mode after it is set",
+    RPC_E_DISCONNECTED = 0x80010108 => "The object invoked has disconnected from its clients",
+    RPC_E_WRONG_THREAD = 0x8001010E => "The application called an interface that was marshalled for a different thread",
+    DISP_E_UNKNOWNINTERFACE = 0x80020001 => "Unknown interface",
+    DISP_E_MEMBERNOTFOUND = 0x80020003 => "Member not found",
+    DISP_E_PARAMNOTFOUND = 0x80020004 => "Parameter not found",
+    DISP_E_TYPEMISMATCH = 0x80020005 => "Type mismatch",
+    DISP_E_UNKNOWNNAME = 0x80020006 => "Unknown name",
+    DISP_E_EXCEPTION = 0x80020009 => "Exception occurred",
+    DISP_E_BADPARAMCOUNT = 0x8002000E => "Invalid number of parameters",
+    STG_E_FILENOTFOUND = 0x80030002 => "The file could not be found",
+    STG_E_ACCESSDENIED = 0x80030005 => "Access denied",
+    STG_E_INSUFFICIENTMEMORY = 0x80030008 => "There is insufficient memory available to complete operation",
+    STG_E_INVALIDPOINTER = 0x80030009 => "Invalid pointer error",
+    STG_E_MEDIUMFULL = 0x80030070 => "There is insufficient disk space to complete operation",
+    CLASS_E_NOAGGREGATION = 0x80040110 => "Class does not support aggregation (or class object is remote)",
+    CLASS_E_CLASSNOTAVAILABLE = 0x80040111 => "ClassFactory cannot supply requested class",
+    REGDB_E_CLASSNOTREG = 0x80040154 => "Class not registered",
+    CO_E_NOTINITIALIZED = 0x800401F0 => "CoInitialize has not been called",
+    CO_E_ALREADYINITIALIZED = 0x800401F1 => "CoInitialize has already been called",
+    E_ACCESSDENIED = 0x80070005 => "General access denied error",
+    E_HANDLE = 0x80070006 => "Handle that is not valid",
+    E_OUTOFMEMORY = 0x8007000E => "Failed to allocate necessary memory",
+    E_INVALIDARG = 0x80070057 => "One or more arguments are not valid",
+    E_NOT_SUFFICIENT_BUFFER = 0x8007007A => "The data area passed to a system call is too small",
+    E_NOT_SET = 0x80070490 => "Element not found",
+    E_NOT_VALID_STATE = 0x8007139F => "The group or resource is not in the correct state to perform the requested operation",
+}
+
+///Facility of Win32 error codes.
+pub const FACILITY_WIN32: u16 = 7;
+
+const FACILITIES: &[(u16, &str)] = &[
+    (0, "FACILITY_NULL"),
+    (1, "FACILITY_RPC"),
+    (2, "FACILITY_DISPATCH"),
+    (3, "FACILITY_STORAGE"),
+    (4, "FACILITY_ITF"),
+    (FACILITY_WIN32, "FACILITY_WIN32"),
+    (8, "FACILITY_WINDOWS"),
+    (9, "FACILITY_SECURITY"),
+    (10, "FACILITY_CONTROL"),
+    (11, "FACILITY_CERT"),
+    (12, "FACILITY_INTERNET"),
+    (13, "FACILITY_MEDIASERVER"),
+    (14, "FACILITY_MSMQ"),
+    (15, "FACILITY_SETUPAPI"),
+    (16, "FACILITY_SCARD"),
+    (17, "FACILITY_COMPLUS"),
+    (18, "FACILITY_AAF"),
+    (19, "FACILITY_URT"),
+    (20, "FACILITY_ACS"),
+    (21, "FACILITY_DPLAY"),
+    (22, "FACILITY_UMI"),
+    (23, "FACILITY_SXS"),
+    (24, "FACILITY_WINDOWS_CE"),
+    (25, "FACILITY_HTTP"),
+    (26, "FACILITY_USERMODE_COMMONLOG"),
+    (31, "FACILITY_USERMODE_FILTER_MANAGER"),
+    (32, "FACILITY_BACKGROUNDCOPY"),
+    (33, "FACILITY_CONFIGURATION"),
+    (34, "FACILITY_STATE_MANAGEMENT"),
+    (35, "FACILITY_METADIRECTORY"),
+    (36, "FACILITY_WINDOWSUPDATE"),
+    (37, "FACILITY_DIRECTORYSERVICE"),
+    (38, "FACILITY_GRAPHICS"),
+    (39, "FACILITY_SHELL"),
+    (40, "FACILITY_TPM_SERVICES"),
+    (41, "FACILITY_TPM_SOFTWARE"),
+    (48, "FACILITY_PLA"),
+    (49, "FACILITY_FVE"),
+    (50, "FACILITY_FWP"),
+    (51, "FACILITY_WINRM"),
+    (52, "FACILITY_NDIS"),
+    (53, "FACILITY_USERMODE_HYPERVISOR"),
+    (54, "FACILITY_CMI"),
+    (55, "FACILITY_USERMODE_VIRTUALIZATION"),
+    (56, "FACILITY_USERMODE_VOLMGR"),
+    (57, "FACILITY_BCD"),
+    (58, "FACILITY_USERMODE_VHD"),
+    (60, "FACILITY_SDIAG"),
+    (61, "FACILITY_WEBSERVICES"),
+    (80, "FACILITY_WINDOWS_DEFENDER"),
+];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Severity of HRESULT.
+pub enum Severity {
+    ///Operation succeeded.
+    Success,
+    ///Operation failed.
+    Failure,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+///HRESULT, decomposed into its severity, facility and code.
+///
+///Displayed as hexadecimal number.
+pub struct HResult(c_int);
+
+impl HResult {
+    #[inline(always)]
+    ///Creates new instance from raw value.
+    pub const fn new(raw: c_int) -> Self {
+        Self(raw)
+    }
+
+    #[inline]
+    ///Creates new instance from its parts, as `MAKE_HRESULT`.
+    pub const fn from_parts(severity: Severity, facility: u16, code: u16) -> Self {
+        let severity = match severity {
+            Severity::Success => 0,
+            Severity::Failure => 0x8000_0000u32,
+        };
+        Self((severity | ((facility as u32 & 0x1FFF) << 16) | code as u32) as c_int)
+    }
+
+    #[inline]
+    ///Converts Win32 error code into HRESULT, as `HRESULT_FROM_WIN32`.
+    pub const fn from_win32(code: c_int) -> Self {
+        if code <= 0 {
+            Self(code)
+        } else {
+            Self::from_parts(Severity::Failure, FACILITY_WIN32, code as u16)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns raw value.
+    pub const fn raw(self) -> c_int {
+        self.0
+    }
+
+    #[inline(always)]
+    ///Returns severity.
+    pub const fn severity(self) -> Severity {
+        if self.0 < 0 {
+            Severity::Failure
+        } else {
+            Severity::Success
+        }
+    }
+
+    #[inline(always)]
+    ///Returns `true` if HRESULT indicates failure, as `FAILED`.
+    pub const fn is_failure(self) -> bool {
+        self.0 < 0
+    }
+
+    #[inline(always)]
+    ///Returns `true` if customer bit is set, indicating HRESULT is not defined by Microsoft.
+    pub const fn is_customer(self) -> bool {
+        self.0 as u32 & 0x2000_0000 != 0
+    }
+
+    #[inline(always)]
+    ///Returns facility.
+    pub const fn facility(self) -> u16 {
+        ((self.0 as u32 >> 16) & 0x1FFF) as u16
+    }
+
+    #[inline(always)]
+    ///Returns facility specific code.
+    pub const fn code(self) -> u16 {
+        self.0 as u16
+    }
+
+    #[inline]
+    ///Returns symbolic name of facility, if it is known.
+    pub fn facility_name(self) -> Option<&'static str> {
+        let facility = self.facility();
+        FACILITIES.iter().find(|entry| entry.0 == facility).map(|entry| entry.1)
+    }
+
+    #[inline]
+    ///Returns symbolic name, if HRESULT is known.
+    pub fn name(self) -> Option<&'static str> {
+        TABLE.iter().find(|entry| entry.0 == self.0).map(|entry| entry.1)
+    }
+
+    ///Returns English description, if HRESULT is known.
+    ///
+    ///Failures of `FACILITY_WIN32` are described as corresponding Win32 error codes.
+    pub fn description(self) -> Option<&'static str> {
+        match TABLE.iter().find(|entry| entry.0 == self.0) {
+            Some(entry) => Some(entry.2),
+            None => match self.to_win32() {
+                Some(code) => crate::win32::description(code.raw_code()),
+                None => None,
+            },
+        }
+    }
+
+    #[inline]
+    ///Converts HRESULT of `FACILITY_WIN32` back into Win32 error code.
+    ///
+    ///Returned error code belongs to [WIN32_CATEGORY](../static.WIN32_CATEGORY.html), which is equal
+    ///to `SYSTEM_CATEGORY` on Windows.
+    pub fn to_win32(self) -> Option<ErrorCode> {
+        if self.0 == S_OK {
+            Some(ErrorCode::new(0, &WIN32_CATEGORY))
+        } else if self.is_failure() && self.facility() == FACILITY_WIN32 {
+            Some(ErrorCode::new(self.code() as c_int, &WIN32_CATEGORY))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    ///Converts into error code
+    pub fn into_error_code(self) -> ErrorCode {
+        ErrorCode::new(self.0, &HRESULT_CATEGORY)
+    }
+}
+
+impl From<HResult> for ErrorCode {
+    #[inline(always)]
+    fn from(this: HResult) -> Self {
+        this.into_error_code()
+    }
+}
+
+impl fmt::Debug for HResult {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("HResult({})", self))
+    }
+}
+
+impl fmt::Display for HResult {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("0x{:08X}", self.0))
+    }
+}
+
+impl fmt::LowerHex for HResult {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, fmt)
+    }
+}
+
+impl fmt::UpperHex for HResult {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, fmt)
+    }
+}
+
+fn equivalent(code: c_int, other: &ErrorCode) -> bool {
+    ptr::eq(&HRESULT_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+fn is_would_block(code: c_int) -> bool {
+    code == E_PENDING || code == HResult::from_win32(crate::win32::WSAEWOULDBLOCK).raw()
+}
+
+fn message(code: c_int, out: &mut MessageBuf) -> &str {
+    let mut writer = MessageWriter::new(out);
+    let _ = write_message(code, &mut writer);
+    writer.finish()
+}
+
+fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    let code = HResult::new(code);
+    match code.facility_name() {
+        Some(facility) => out.write_fmt(format_args!("{} {}: ", code, facility))?,
+        None => out.write_fmt(format_args!("{} FACILITY_{}: ", code, code.facility()))?,
+    }
+    out.write_str(code.description().unwrap_or_else(|| generic_map_error_code(if code.is_failure() { code.raw() } else { 0 })))
+}
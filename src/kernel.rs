@@ -0,0 +1,139 @@
+//!Linux kernel error category
+//!
+//!Describes kernel-internal error codes, which are not part of userspace ABI, but can still be
+//!observed in results of `io_uring`, eBPF helpers or netlink.
+use crate::{Category, ErrorCode, MessageBuf};
+use crate::utils::{write_message_buf, generic_map_error_code};
+use crate::types::{c_int, c_uint};
+
+use core::{fmt, ptr};
+
+///Kernel error category, describing Linux kernel-internal error codes on any platform.
+///
+///Use [ErrorCode::from_neg_errno](../struct.ErrorCode.html#method.from_neg_errno) to convert
+///kernel-style result into error code of appropriate category.
+///
+///## Usage
+///
+///```rust
+///use error_code::{ErrorCode, KERNEL_CATEGORY};
+///use error_code::kernel;
+///
+///let error = ErrorCode::from_neg_errno(-kernel::EPROBE_DEFER).expect("to be error");
+///assert!(error.is_category(&KERNEL_CATEGORY));
+///assert_eq!(error.to_string(), "KernelError(517): Driver requests probe retry");
+///assert_eq!(kernel::name(error.raw_code()), Some("EPROBE_DEFER"));
+///```
+pub static KERNEL_CATEGORY: Category = Category {
+    name: "KernelError",
+    message,
+    equivalent,
+    is_would_block,
+    write_message: Some(write_message),
+};
+
+macro_rules! define_kernel {
+    ($($name:ident = $code:literal => $message:literal,)+) => {
+        $(
+            #[doc = $message]
+            pub const $name: c_int = $code;
+        )+
+
+        const TABLE: &[(c_int, &str, &str)] = &[
+            $(
+                ($code, stringify!($name), $message),
+            )+
+        ];
+    };
+}
+
+define_kernel! {
+    ERESTARTSYS = 512 => "Restart system call if signal handler has SA_RESTART",
+    ERESTARTNOINTR = 513 => "Restart system call unconditionally",
+    ERESTARTNOHAND = 514 => "Restart system call if there is no signal handler",
+    ENOIOCTLCMD = 515 => "No ioctl command",
+    ERESTART_RESTARTBLOCK = 516 => "Restart system call by calling sys_restart_syscall",
+    EPROBE_DEFER = 517 => "Driver requests probe retry",
+    EOPENSTALE = 518 => "Open found a stale dentry",
+    ENOPARAM = 519 => "Parameter not supported",
+    EBADHANDLE = 521 => "Illegal NFS file handle",
+    ENOTSYNC = 522 => "Update synchronization mismatch",
+    EBADCOOKIE = 523 => "Cookie is stale",
+    ENOTSUPP = 524 => "Operation is not supported",
+    ETOOSMALL = 525 => "Buffer or request is too small",
+    ESERVERFAULT = 526 => "An untranslatable error occurred",
+    EBADTYPE = 527 => "Type not supported by server",
+    EJUKEBOX = 528 => "Request initiated, but will not complete before timeout",
+    EIOCBQUEUED = 529 => "iocb queued, will get completion event",
+    ERECALLCONFLICT = 530 => "Conflict with recalled state",
+    ENOGRACE = 531 => "NFS file lock reclaim refused",
+}
+
+///Largest error code, that can be returned by kernel as negative value.
+pub const MAX_ERRNO: c_int = 4095;
+
+#[inline]
+fn lookup(code: c_int) -> Option<&'static (c_int, &'static str, &'static str)> {
+    TABLE.iter().find(|entry| entry.0 == code)
+}
+
+#[inline]
+///Returns `true` if `code` is known kernel-internal error code.
+pub const fn is_internal(code: c_int) -> bool {
+    let mut idx = 0;
+    while idx < TABLE.len() {
+        if TABLE[idx].0 == code {
+            return true;
+        }
+        idx += 1;
+    }
+    false
+}
+
+#[inline]
+///Returns symbolic name of kernel-internal error code, if it is known.
+pub fn name(code: c_int) -> Option<&'static str> {
+    lookup(code).map(|entry| entry.1)
+}
+
+#[inline]
+///Returns description of kernel-internal error code, if it is known.
+pub fn description(code: c_int) -> Option<&'static str> {
+    lookup(code).map(|entry| entry.2)
+}
+
+///Converts kernel-style result (e.g. `res` of `io_uring` completion) into `Result`.
+///
+///Negative value is converted using [ErrorCode::from_neg_errno](../struct.ErrorCode.html#method.from_neg_errno),
+///while any other value is returned as it is, reinterpreted as unsigned.
+///
+///```rust
+///use error_code::{defs, ErrorCode};
+///use error_code::kernel;
+///
+///assert_eq!(kernel::result(10), Ok(10));
+///assert_eq!(kernel::result(-kernel::MAX_ERRNO - 1), Ok(0xFFFF_F000));
+///assert_eq!(kernel::result(-defs::EAGAIN), Err(ErrorCode::new_posix(defs::EAGAIN)));
+///```
+pub fn result(res: c_int) -> Result<c_uint, ErrorCode> {
+    match ErrorCode::from_neg_errno(res) {
+        Some(error) => Err(error),
+        None => Ok(res as c_uint),
+    }
+}
+
+fn equivalent(code: c_int, other: &ErrorCode) -> bool {
+    ptr::eq(&KERNEL_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+fn is_would_block(code: c_int) -> bool {
+    code == EJUKEBOX
+}
+
+fn message(code: c_int, out: &mut MessageBuf) -> &str {
+    write_message_buf(out, description(code).unwrap_or_else(|| generic_map_error_code(code)))
+}
+
+fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    out.write_str(description(code).unwrap_or_else(|| generic_map_error_code(code)))
+}
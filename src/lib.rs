@@ -15,7 +15,6 @@
 
 #![no_std]
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -28,18 +27,55 @@ pub const UNKNOWN_ERROR: &str = "Unknown error";
 ///Text to return when error fails to be converted into utf-8
 pub const FAIL_ERROR_FORMAT: &str = "Failed to format error into utf-8";
 
+///Text appended to error message when it is truncated to fit [MessageBuf](type.MessageBuf.html)
+pub const TRUNCATED_MARKER: &str = "…";
+
 ///Error message buffer size
 pub const MESSAGE_BUF_SIZE: usize = 256;
 ///Type alias for buffer to hold error code description.
 pub type MessageBuf = [mem::MaybeUninit<u8>; MESSAGE_BUF_SIZE];
 
 pub mod defs;
+#[doc(hidden)]
+///Names of `SYSTEM` error codes, used by macros.
+pub mod system_defs {
+    #[cfg(not(windows))]
+    pub use crate::defs::*;
+    #[cfg(windows)]
+    pub use crate::win32::*;
+}
 pub mod types;
 pub mod utils;
-mod posix;
+mod message;
+pub use message::Message;
+mod category;
+pub use category::ErrorCategory;
+#[cfg(feature = "derive")]
+pub use error_code_derive::ErrorCategory;
+mod ext;
+pub use ext::{ContextError, ResultExt};
+pub mod posix;
 pub use posix::POSIX_CATEGORY;
 mod system;
 pub use system::SYSTEM_CATEGORY;
+pub mod win32;
+pub use win32::WIN32_CATEGORY;
+pub mod hresult;
+pub use hresult::HRESULT_CATEGORY;
+pub mod ntstatus;
+pub use ntstatus::NTSTATUS_CATEGORY;
+pub mod netdb;
+pub use netdb::NETDB_CATEGORY;
+pub mod kernel;
+pub use kernel::KERNEL_CATEGORY;
+pub mod signal;
+pub use signal::SIGNAL_CATEGORY;
+pub mod exit;
+pub use exit::EXIT_CATEGORY;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "std")]
+pub use report::{ExitError, Report};
 
 #[macro_export]
 ///Defines error code `Category` as enum which implements conversion into generic ErrorCode
@@ -61,7 +97,8 @@ pub use system::SYSTEM_CATEGORY;
 ///define_category!(
 ///    ///This is documentation for my error
 ///    ///
-///    ///Documentation of variants only allow 1 line comment and it should be within 256 characters
+///    ///Documentation of variants only allow 1 line comment.
+///    ///Messages longer than `MESSAGE_BUF_SIZE` are truncated when written into `MessageBuf`.
 ///    pub enum MyError {
 ///        ///Success
 ///        Success = 0,
@@ -79,8 +116,91 @@ pub use system::SYSTEM_CATEGORY;
 ///assert_eq!(error.to_string(), "MyError(1): This is bad");
 ///assert_eq!(error.to_string(), MyError::Error.to_string());
 ///```
+///
+///# Ranges
+///
+///After variants, it is possible to specify messages for ranges of codes, as well as default message.
+///
+///Variants take priority over ranges, and unmapped codes without default message are described
+///using [generic_map_error_code](utils/fn.generic_map_error_code.html).
+///
+///```
+///use error_code::{define_category, ErrorCode};
+///
+///define_category!(
+///    ///HTTP status codes
+///    pub enum HttpStatus {
+///        ///OK
+///        Ok = 200,
+///        ///Not Found
+///        NotFound = 404,
+///    }
+///    100..=199 => "Informational",
+///    400..=499 => "Client error",
+///    _ => "Unknown status",
+///);
+///
+///assert_eq!(HttpStatus::map_code(404), Some("Not Found"));
+///assert_eq!(HttpStatus::map_code(101), Some("Informational"));
+///assert_eq!(HttpStatus::map_code(418), Some("Client error"));
+///assert_eq!(HttpStatus::map_code(302), Some("Unknown status"));
+///assert_eq!(ErrorCode::new(418, HttpStatus::Ok.into_error_code().category()).to_string(), "HttpStatus(418): Client error");
+///```
+///
+///# Validation
+///
+///Category fails to compile if message is empty (duplicate codes are already rejected as duplicate enum discriminants):
+///
+///```compile_fail
+///error_code::define_category!(
+///    pub enum MyError {
+///        ///
+///        Empty = 1,
+///    }
+///);
+///```
+///
+///Ranges must not be empty or overlap with each other:
+///
+///```compile_fail
+///error_code::define_category!(
+///    pub enum MyError {
+///        ///Bad
+///        Error = 1,
+///    }
+///    100..=199 => "Bad range",
+///    150..=299 => "Overlapping range",
+///);
+///```
+///
+///Optionally it is possible to require all messages to be unique:
+///
+///```compile_fail
+///error_code::define_category!(
+///    #![unique_messages]
+///    pub enum MyError {
+///        ///Bad
+///        Error = 1,
+///        ///Bad
+///        AnotherError = 2,
+///    }
+///);
+///```
 macro_rules! define_category {
+    (@unique_messages) => {
+        false
+    };
+    (@unique_messages unique_messages) => {
+        true
+    };
+    (@default) => {
+        None
+    };
+    (@default $default:literal) => {
+        Some($default)
+    };
     (
+        @impl $($unique:ident)?;
         $(#[$docs:meta])*
         pub enum $name:ident {
             $(
@@ -88,6 +208,12 @@ macro_rules! define_category {
                 $ident:ident = $code:literal,
              )+
         }
+        $(
+            $start:literal..=$end:literal => $range_msg:literal,
+        )*
+        $(
+            _ => $default:literal,
+        )?
     ) => {
         #[derive(Copy, Clone, PartialEq, Eq, Debug, PartialOrd, Ord)]
         #[repr(i32)]
@@ -99,6 +225,16 @@ macro_rules! define_category {
             )+
         }
 
+        const _: () = {
+            const TABLE: &[($crate::types::c_int, &str)] = &[$(($code, $msg),)+];
+            assert!(!$crate::utils::has_blank_messages(TABLE), core::concat!(core::stringify!($name), " has empty messages"));
+            assert!(!$crate::define_category!(@unique_messages $($unique)?) || !$crate::utils::has_duplicate_messages(TABLE), core::concat!(core::stringify!($name), " has duplicate messages"));
+
+            const RANGES: &[($crate::types::c_int, $crate::types::c_int, &str)] = &[$(($start, $end, $range_msg),)*];
+            assert!(!$crate::utils::has_invalid_ranges(RANGES), core::concat!(core::stringify!($name), " has empty or overlapping ranges"));
+            assert!(!$crate::utils::has_blank_messages(&[$(($start, $range_msg),)* $((0, $default),)?]), core::concat!(core::stringify!($name), " has empty messages"));
+        };
+
         impl From<$name> for $crate::ErrorCode {
             #[inline(always)]
             fn from(this: $name) -> $crate::ErrorCode {
@@ -114,19 +250,20 @@ macro_rules! define_category {
         }
 
         impl $name {
-            const _ASSERT: () = {
-                $(
-                    assert!($msg.len() <= $crate::MESSAGE_BUF_SIZE, "Message buffer overflow, make sure your messages are not beyond MESSAGE_BUF_SIZE");
-                )+
-            };
-
-
             #[inline(always)]
             ///Map raw error code to textual representation.
             pub fn map_code(code: $crate::types::c_int) -> Option<&'static str> {
                 match code {
                     $($code => Some($msg),)+
-                    _ => None,
+                    $($start..=$end => Some($range_msg),)*
+                    _ => $crate::define_category!(@default $($default)?),
+                }
+            }
+
+            fn write_message(code: $crate::types::c_int, out: &mut dyn core::fmt::Write) -> core::fmt::Result {
+                match Self::map_code(code) {
+                    Some(msg) => out.write_str(msg),
+                    None => out.write_str($crate::utils::generic_map_error_code(code)),
                 }
             }
 
@@ -136,24 +273,17 @@ macro_rules! define_category {
                     None => $crate::utils::generic_map_error_code(code),
                 };
 
-                debug_assert!(msg.len() <= out.len());
-                unsafe {
-                    core::ptr::copy_nonoverlapping(msg.as_ptr(), out.as_mut_ptr() as *mut u8, msg.len());
-                    core::str::from_utf8_unchecked(
-                        core::slice::from_raw_parts(out.as_ptr() as *const u8, msg.len())
-                    )
-                }
+                $crate::utils::write_message_buf(out, msg)
             }
 
             ///Converts into error code
             pub fn into_error_code(self) -> $crate::ErrorCode {
-                let _ = Self::_ASSERT;
-
                 static CATEGORY: $crate::Category = $crate::Category {
                     name: core::stringify!($name),
                     message: $name::message,
                     equivalent,
-                    is_would_block
+                    is_would_block,
+                    write_message: Some($name::write_message),
                 };
 
                 fn equivalent(code: $crate::types::c_int, other: &$crate::ErrorCode) -> bool {
@@ -167,26 +297,282 @@ macro_rules! define_category {
                 $crate::ErrorCode::new(self as _, &CATEGORY)
             }
         }
+    };
+    (
+        #![unique_messages]
+        $($rest:tt)+
+    ) => {
+        $crate::define_category!(@impl unique_messages; $($rest)+);
+    };
+    (
+        $($rest:tt)+
+    ) => {
+        $crate::define_category!(@impl ; $($rest)+);
+    };
+}
+
+#[macro_export]
+///Defines `static` error code `Category` from table of raw codes and their messages.
+///
+///Codes absent from the table are described using [generic_map_error_code](utils/fn.generic_map_error_code.html).
+///
+///Table must be an expression of type `&'static [(c_int, &'static str)]`, without duplicate codes or empty messages.
+///
+///This is a macro rather than `const` constructor of `Category`, because `Category` consists of plain
+///function pointers, which cannot capture the table. Hence functions are generated for every table.
+///
+///# Usage
+///
+///```
+///use error_code::{define_table_category, ErrorCode};
+///use error_code::types::c_int;
+///
+///const VENDOR_TABLE: &[(c_int, &str)] = &[
+///    (0, "Success"),
+///    (1, "Device is busy"),
+///    (2, "Device is not connected"),
+///];
+///
+///define_table_category!(
+///    ///Vendor SDK errors
+///    pub static VENDOR_CATEGORY = ("VendorError", VENDOR_TABLE);
+///);
+///
+///define_table_category!(
+///    ///Firmware errors
+///    static FIRMWARE_CATEGORY = ("FirmwareError", &[
+///        (-1, "Checksum mismatch"),
+///    ]);
+///);
+///
+///let error = ErrorCode::new(1, &VENDOR_CATEGORY);
+///assert_eq!(error.to_string(), "VendorError(1): Device is busy");
+///assert_eq!(error, ErrorCode::new(1, &VENDOR_CATEGORY));
+///assert_ne!(error, ErrorCode::new(2, &VENDOR_CATEGORY));
+///
+///let error = ErrorCode::new(-1, &FIRMWARE_CATEGORY);
+///assert_eq!(error.to_string(), "FirmwareError(-1): Checksum mismatch");
+///```
+macro_rules! define_table_category {
+    (
+        $(#[$docs:meta])*
+        $vis:vis static $ident:ident = ($name:expr, $table:expr);
+    ) => {
+        $(#[$docs])*
+        $vis static $ident: $crate::Category = {
+            const TABLE: &[($crate::types::c_int, &str)] = $table;
+            const _: () = {
+                assert!(!$crate::utils::has_duplicate_codes(TABLE), core::concat!(core::stringify!($ident), " has duplicate codes"));
+                assert!(!$crate::utils::has_blank_messages(TABLE), core::concat!(core::stringify!($ident), " has empty messages"));
+            };
+
+            fn map_code(code: $crate::types::c_int) -> &'static str {
+                match $crate::utils::lookup_table(TABLE, code) {
+                    Some(msg) => msg,
+                    None => $crate::utils::generic_map_error_code(code),
+                }
+            }
+
+            fn message(code: $crate::types::c_int, out: &mut $crate::MessageBuf) -> &str {
+                $crate::utils::write_message_buf(out, map_code(code))
+            }
+
+            fn write_message(code: $crate::types::c_int, out: &mut dyn core::fmt::Write) -> core::fmt::Result {
+                out.write_str(map_code(code))
+            }
+
+            fn equivalent(code: $crate::types::c_int, other: &$crate::ErrorCode) -> bool {
+                core::ptr::eq(&$ident, other.category()) && code == other.raw_code()
+            }
+
+            fn is_would_block(_: $crate::types::c_int) -> bool {
+                false
+            }
+
+            $crate::Category {
+                name: $name,
+                message,
+                equivalent,
+                is_would_block,
+                write_message: Some(write_message),
+            }
+        };
     }
 }
 
+#[macro_export]
+///Defines enum representing subset of error codes within `POSIX` or `SYSTEM` category.
+///
+///Codes are specified by name of constant within [defs](defs/index.html) module.
+///On Windows, `SYSTEM` error codes are Win32 error codes, hence names of `SYSTEM` codes are
+///resolved through [win32](win32/index.html) module instead.
+///
+///Enum implements `Copy`, `Debug`, `Display`, `Eq`, `Hash`, as well as conversion into `ErrorCode`
+///and `TryFrom<ErrorCode>`, which fails with original error code, if it doesn't belong to the subset.
+///
+///# Usage
+///
+///```
+///use error_code::{define_error_set, ErrorCode};
+///
+///use core::convert::TryFrom;
+///
+///define_error_set!(
+///    ///Errors of non-blocking read
+///    pub enum ReadError: POSIX {
+///        EAGAIN,
+///    }
+///);
+///
+///fn read() -> Result<(), ReadError> {
+///    Err(ReadError::EAGAIN)
+///}
+///
+///match read() {
+///    Ok(()) => (),
+///    Err(ReadError::EAGAIN) => (),
+///}
+///
+///let error = ErrorCode::from(ReadError::EAGAIN);
+///assert_eq!(ReadError::try_from(error), Ok(ReadError::EAGAIN));
+///assert_eq!(ReadError::try_from(ErrorCode::new_posix(0)), Err(ErrorCode::new_posix(0)));
+///```
+macro_rules! define_error_set {
+    (@category POSIX) => {
+        $crate::POSIX_CATEGORY
+    };
+    (@category SYSTEM) => {
+        $crate::SYSTEM_CATEGORY
+    };
+    (@code POSIX $code:ident) => {
+        $crate::defs::$code
+    };
+    (@code SYSTEM $code:ident) => {
+        $crate::system_defs::$code
+    };
+    (
+        $(#[$docs:meta])*
+        $vis:vis enum $name:ident: $category:ident {
+            $($code:ident),+ $(,)?
+        }
+    ) => {
+        #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+        #[allow(non_camel_case_types)]
+        $(#[$docs])*
+        $vis enum $name {
+            $(
+                #[doc = core::stringify!($code)]
+                $code,
+            )+
+        }
+
+        impl $name {
+            #[inline]
+            ///Returns raw error code.
+            pub const fn raw_code(self) -> $crate::types::c_int {
+                match self {
+                    $(
+                        $name::$code => $crate::define_error_set!(@code $category $code),
+                    )+
+                }
+            }
+
+            #[inline]
+            ///Converts into error code
+            pub fn into_error_code(self) -> $crate::ErrorCode {
+                $crate::ErrorCode::new(self.raw_code(), &$crate::define_error_set!(@category $category))
+            }
+        }
+
+        impl From<$name> for $crate::ErrorCode {
+            #[inline(always)]
+            fn from(this: $name) -> $crate::ErrorCode {
+                this.into_error_code()
+            }
+        }
+
+        impl core::convert::TryFrom<$crate::ErrorCode> for $name {
+            type Error = $crate::ErrorCode;
+
+            fn try_from(code: $crate::ErrorCode) -> Result<Self, Self::Error> {
+                $(
+                    if code == $name::$code.into_error_code() {
+                        return Ok($name::$code);
+                    }
+                )+
+                Err(code)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            #[inline(always)]
+            fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.into_error_code(), fmt)
+            }
+        }
+    };
+}
+
+#[macro_export]
+///Checks whether error code belongs to `POSIX` or `SYSTEM` category and matches any of specified codes.
+///
+///Codes are specified either by name of constant or as literal, including negative one (e.g. `-1`).
+///Names are resolved same way as in [define_error_set](macro.define_error_set.html).
+///
+///# Usage
+///
+///```
+///use error_code::{defs, ErrorCode};
+///
+///let error = ErrorCode::new_posix(defs::EAGAIN);
+///assert!(error_code::matches_code!(error, POSIX: EAGAIN | EWOULDBLOCK));
+///assert!(error_code::matches_code!(&error, POSIX: 0 | EAGAIN));
+///assert!(!error_code::matches_code!(error, POSIX: 0));
+///assert!(!error_code::matches_code!(ErrorCode::new_system(0), POSIX: 0));
+///assert!(error_code::matches_code!(ErrorCode::new_posix(-1), POSIX: EAGAIN | -1));
+///```
+macro_rules! matches_code {
+    (@code $category:ident $code:ident) => {
+        $crate::define_error_set!(@code $category $code)
+    };
+    (@code $category:ident $code:literal) => {{
+        const CODE: $crate::types::c_int = $code;
+        CODE
+    }};
+    (@any $error:ident $category:ident $code:ident $(| $($rest:tt)+)?) => {
+        $error.raw_code() == $crate::matches_code!(@code $category $code) $(|| $crate::matches_code!(@any $error $category $($rest)+))?
+    };
+    (@any $error:ident $category:ident $code:literal $(| $($rest:tt)+)?) => {
+        $error.raw_code() == $crate::matches_code!(@code $category $code) $(|| $crate::matches_code!(@any $error $category $($rest)+))?
+    };
+    ($error:expr, $category:ident: $($codes:tt)+) => {{
+        let error = &$error;
+        error.is_category(&$crate::define_error_set!(@category $category)) && ($crate::matches_code!(@any error $category $($codes)+))
+    }};
+}
+
 ///Interface for error category
 ///
 ///It is implemented as pointers in order to avoid generics or overhead of fat pointers.
 ///
+///Alternatively category can be defined via [ErrorCategory](trait.ErrorCategory.html) trait.
+///
 ///## Custom implementation example
 ///
 ///```rust
 ///use error_code::{ErrorCode, Category};
 ///use error_code::types::c_int;
+///use error_code::utils::MessageWriter;
 ///
 ///use core::ptr;
+///use core::fmt::Write;
 ///
 ///static MY_CATEGORY: Category = Category {
 ///    name: "MyError",
 ///    message,
 ///    equivalent,
-///    is_would_block
+///    is_would_block,
+///    write_message: None,
 ///};
 ///
 ///fn equivalent(code: c_int, other: &ErrorCode) -> bool {
@@ -198,23 +584,21 @@ macro_rules! define_category {
 ///}
 ///
 ///fn message(code: c_int, out: &mut error_code::MessageBuf) -> &str {
-///    let msg = match code {
-///        0 => "Success",
-///        1 => "Bad",
-///        _ => "Whatever",
+///    let mut writer = MessageWriter::new(out);
+///    let _ = match code {
+///        0 => writer.write_str("Success"),
+///        1 => writer.write_str("Bad"),
+///        code => write!(writer, "Device {} timed out", code),
 ///    };
-///
-///    debug_assert!(msg.len() <= out.len());
-///    unsafe {
-///        ptr::copy_nonoverlapping(msg.as_ptr(), out.as_mut_ptr() as *mut u8, msg.len())
-///    }
-///    msg
+///    writer.finish()
 ///}
 ///
 ///#[inline(always)]
 ///pub fn my_error(code: c_int) -> ErrorCode {
 ///    ErrorCode::new(code, &MY_CATEGORY)
 ///}
+///
+///assert_eq!(my_error(3).to_string(), "MyError(3): Device 3 timed out");
 ///```
 pub struct Category {
     ///Category name
@@ -243,6 +627,13 @@ pub struct Category {
     ///
     ///This should `true` only for errors that indicate operation can be re-tried later.
     pub is_would_block: fn(types::c_int) -> bool,
+    ///Writes descriptive error message directly into `out`.
+    ///
+    ///When specified, it is used instead of `message` to format error code, hence message is not
+    ///limited by [MESSAGE_BUF_SIZE](constant.MESSAGE_BUF_SIZE.html).
+    ///
+    ///When `None`, formatting falls back to `message`.
+    pub write_message: Option<fn(types::c_int, &mut dyn fmt::Write) -> fmt::Result>,
 }
 
 #[derive(Copy, Clone)]
@@ -274,6 +665,67 @@ impl ErrorCode {
         Self::new(code, &SYSTEM_CATEGORY)
     }
 
+    #[inline]
+    ///Creates error code from kernel-style result, where error is reported as negated error code.
+    ///
+    ///This convention is used by `io_uring` completion's `res`, eBPF helpers and netlink's `nlmsgerr.error`.
+    ///
+    ///Following kernel's `IS_ERR_VALUE`, only values in range `-MAX_ERRNO..=-1` are errors, so
+    ///`None` is returned for anything else (refer to [kernel::MAX_ERRNO](kernel/constant.MAX_ERRNO.html)).
+    ///Kernel-internal error codes (e.g. `ERESTARTSYS`) belong to [KERNEL_CATEGORY](static.KERNEL_CATEGORY.html),
+    ///while the rest are `POSIX` error codes.
+    ///
+    ///```rust
+    ///use error_code::{defs, ErrorCode};
+    ///use error_code::types::c_int;
+    ///
+    ///assert_eq!(ErrorCode::from_neg_errno(-defs::EAGAIN), Some(ErrorCode::new_posix(defs::EAGAIN)));
+    ///assert_eq!(ErrorCode::from_neg_errno(0), None);
+    ///assert_eq!(ErrorCode::from_neg_errno(c_int::MIN), None);
+    ///```
+    pub fn from_neg_errno(res: types::c_int) -> Option<Self> {
+        if !(-kernel::MAX_ERRNO..0).contains(&res) {
+            return None;
+        }
+
+        let code = -res;
+        if kernel::is_internal(code) {
+            Some(Self::new(code, &KERNEL_CATEGORY))
+        } else {
+            Some(Self::new_posix(code))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    ///Creates error code from exit status of child process.
+    ///
+    ///Returns `None` if process exited successfully.
+    ///
+    ///- Non-zero exit code belongs to [EXIT_CATEGORY](static.EXIT_CATEGORY.html);
+    ///- On Unix, termination by signal belongs to [SIGNAL_CATEGORY](static.SIGNAL_CATEGORY.html);
+    ///- On Windows, exit code that is known `NTSTATUS` error (e.g. `STATUS_ACCESS_VIOLATION`) belongs to [NTSTATUS_CATEGORY](static.NTSTATUS_CATEGORY.html);
+    ///- Process without exit code (e.g. stopped one) is reported as [exit::NOT_EXITED](exit/constant.NOT_EXITED.html).
+    pub fn from_exit_status(status: std::process::ExitStatus) -> Option<Self> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+
+            if let Some(signal) = status.signal() {
+                return Some(Self::new(signal, &SIGNAL_CATEGORY));
+            }
+        }
+
+        match status.code() {
+            Some(0) => None,
+            #[cfg(windows)]
+            //Only known codes, as process can exit with arbitrary code (e.g. `exit(-1)`)
+            Some(code) if ntstatus::NtStatus::new(code).name().is_some() => Some(Self::new(code, &NTSTATUS_CATEGORY)),
+            Some(code) => Some(Self::new(code, &EXIT_CATEGORY)),
+            //Process was neither exited nor signaled (e.g. stopped), which is not success
+            None => Some(Self::new(exit::NOT_EXITED, &EXIT_CATEGORY)),
+        }
+    }
+
     #[inline]
     ///Gets last POSIX error
     pub fn last_posix() -> Self {
@@ -298,6 +750,87 @@ impl ErrorCode {
         self.category
     }
 
+    #[inline]
+    ///Gets error message, without category's name and raw code.
+    pub fn message(&self) -> Message {
+        let mut message = Message::default();
+        let _ = self.write_message(&mut message);
+        message
+    }
+
+    ///Writes error message into `out`, using streaming hook of the category, if available.
+    pub fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        match self.category.write_message {
+            Some(write_message) => write_message(self.code, out),
+            None => {
+                let mut buf = [mem::MaybeUninit::uninit(); MESSAGE_BUF_SIZE];
+                out.write_str((self.category.message)(self.code, &mut buf))
+            }
+        }
+    }
+
+    #[inline(always)]
+    ///Returns `true` if error code belongs to specified `category`.
+    pub fn is_category(&self, category: &Category) -> bool {
+        core::ptr::eq(self.category, category)
+    }
+
+    #[inline]
+    ///Returns typed POSIX error code, if error code belongs to `POSIX` category or `SYSTEM`
+    ///category on platform other than Windows.
+    pub fn as_errno(&self) -> Option<posix::Errno> {
+        if self.is_posix_like() {
+            posix::Errno::from_raw(self.code)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Converts POSIX error code into portable `POSIX` error code, using Linux numbering.
+    ///
+    ///Returns `None` if error code is not POSIX one (as in [as_errno](#method.as_errno)) or it is not known.
+    ///
+    ///Use [posix::translate](posix/fn.translate.html) to convert portable code into numbering of particular platform.
+    pub fn to_portable(&self) -> Option<Self> {
+        self.as_errno().and_then(|errno| errno.raw_in(posix::Platform::Linux)).map(Self::new_posix)
+    }
+
+    #[inline]
+    ///Replaces POSIX error code, that is alias of another one, with the canonical one (e.g. `EWOULDBLOCK` with `EAGAIN`).
+    ///
+    ///Error codes of other categories are returned as it is.
+    ///
+    ///Refer to [posix::normalize](posix/fn.normalize.html) for list of aliases.
+    ///
+    ///Normalized error codes can be used as keys, to count aliases as the same error.
+    pub fn normalize(&self) -> Self {
+        if self.is_posix_like() {
+            Self::new(posix::normalize(self.code), self.category)
+        } else {
+            *self
+        }
+    }
+
+    #[inline]
+    ///Compares error codes, considering aliases (e.g. `EAGAIN` and `EWOULDBLOCK`) to be equal.
+    ///
+    ///```rust
+    ///use error_code::{defs, ErrorCode};
+    ///
+    ///let error = ErrorCode::new_posix(defs::EWOULDBLOCK);
+    ///assert!(error.eq_normalized(&ErrorCode::new_posix(defs::EAGAIN)));
+    ///assert_eq!(error.normalize(), ErrorCode::new_posix(defs::EAGAIN));
+    ///```
+    pub fn eq_normalized(&self, other: &Self) -> bool {
+        self.normalize() == other.normalize()
+    }
+
+    #[inline(always)]
+    fn is_posix_like(&self) -> bool {
+        self.is_category(&POSIX_CATEGORY) || (cfg!(not(windows)) && self.is_category(&SYSTEM_CATEGORY))
+    }
+
     #[inline(always)]
     ///Returns `true` if underlying error indicates operation can or should be re-tried at later date.
     pub fn is_would_block(&self) -> bool {
@@ -324,17 +857,14 @@ impl hash::Hash for ErrorCode {
 impl fmt::Debug for ErrorCode {
     #[inline]
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut out = [mem::MaybeUninit::uninit(); MESSAGE_BUF_SIZE];
-        let message = (self.category.message)(self.code, &mut out);
-        fmt.debug_struct(self.category.name).field("code", &self.code).field("message", &message).finish()
+        fmt.debug_struct(self.category.name).field("code", &self.code).field("message", &utils::DebugMessage(self)).finish()
     }
 }
 
 impl fmt::Display for ErrorCode {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut out = [mem::MaybeUninit::uninit(); MESSAGE_BUF_SIZE];
-        let message = (self.category.message)(self.code, &mut out);
-        fmt.write_fmt(format_args!("{}({}): {}", self.category.name, self.code, message))
+        fmt.write_fmt(format_args!("{}({}): ", self.category.name, self.code))?;
+        self.write_message(fmt)
     }
 }
 
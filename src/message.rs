@@ -0,0 +1,122 @@
+use crate::{MessageBuf, MESSAGE_BUF_SIZE};
+use crate::utils::MessageWriter;
+
+use core::fmt;
+use core::{mem, ops, slice, str};
+
+#[derive(Clone)]
+///Owned error message.
+///
+///Text is stored within [MessageBuf](type.MessageBuf.html), hence it never allocates.
+pub struct Message {
+    buf: MessageBuf,
+    len: usize,
+    truncated: bool,
+}
+
+impl Message {
+    #[inline]
+    ///Creates new message by copying provided `text`.
+    ///
+    ///If `text` doesn't fit [MESSAGE_BUF_SIZE](constant.MESSAGE_BUF_SIZE.html), it is truncated at the closest char boundary
+    ///and ends with [TRUNCATED_MARKER](constant.TRUNCATED_MARKER.html).
+    pub fn new(text: &str) -> Self {
+        let mut this = Self::default();
+        let _ = fmt::Write::write_str(&mut this, text);
+        this
+    }
+
+    #[inline(always)]
+    ///Returns `true` if message had to be truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    #[inline(always)]
+    ///Returns message text.
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(
+                slice::from_raw_parts(self.buf.as_ptr() as *const u8, self.len)
+            )
+        }
+    }
+}
+
+impl Default for Message {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            buf: [mem::MaybeUninit::uninit(); MESSAGE_BUF_SIZE],
+            len: 0,
+            truncated: false,
+        }
+    }
+}
+
+impl fmt::Write for Message {
+    ///Appends `text` to the message.
+    ///
+    ///Once buffer is full, `text` is truncated at the closest char boundary, followed by
+    ///[TRUNCATED_MARKER](constant.TRUNCATED_MARKER.html), and error is returned.
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let mut writer = MessageWriter::with_state(&mut self.buf, self.len, self.truncated);
+        let result = writer.write_str(text);
+        self.len = writer.len();
+        self.truncated = writer.is_truncated();
+        result
+    }
+}
+
+impl ops::Deref for Message {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Message {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for Message {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Message {}
+
+impl PartialEq<str> for Message {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Message {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for Message {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), fmt)
+    }
+}
+
+impl fmt::Display for Message {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), fmt)
+    }
+}
@@ -0,0 +1,326 @@
+//!Name resolution error categories
+//!
+//!Describes errors of `getaddrinfo`/`getnameinfo` (`EAI_*` codes) and legacy resolver functions
+//!such as `gethostbyname` (`h_errno` codes).
+//!
+//!Constants correspond to numbering of target platform.
+//!Unknown targets use numbering of GNU libc.
+use crate::{Category, ErrorCode, MessageBuf};
+use crate::utils::{MessageWriter, generic_map_error_code};
+use crate::types::c_int;
+
+use core::{fmt, ptr};
+
+///Netdb error category, describing `EAI_*` error codes returned by `getaddrinfo` and `getnameinfo`.
+///
+///On hosted Unix messages are taken from `gai_strerror`, otherwise built-in table of English
+///descriptions is used.
+///
+///On Windows error codes of this category are equal to error codes of `SYSTEM_CATEGORY` and `WIN32_CATEGORY`.
+///
+///## Usage
+///
+///```rust
+///use error_code::{ErrorCode, NETDB_CATEGORY};
+///use error_code::netdb;
+///
+///let error = netdb::gai_error(netdb::EAI_AGAIN);
+///assert!(error.is_category(&NETDB_CATEGORY));
+///assert!(error.is_would_block());
+///assert_eq!(netdb::name(error.raw_code()), Some("EAI_AGAIN"));
+///```
+pub static NETDB_CATEGORY: Category = Category {
+    name: "NetdbError",
+    message,
+    equivalent,
+    is_would_block,
+    write_message: Some(write_message),
+};
+
+///Host error category, describing `h_errno` codes set by `gethostbyname` and related functions.
+///
+///Messages are taken from built-in table of English descriptions.
+///
+///## Usage
+///
+///```rust
+///use error_code::ErrorCode;
+///use error_code::netdb::{self, HOST_ERROR_CATEGORY};
+///
+///let error = ErrorCode::new(netdb::HOST_NOT_FOUND, &HOST_ERROR_CATEGORY);
+///assert_eq!(error.message(), "Unknown host");
+///assert!(ErrorCode::new(netdb::TRY_AGAIN, &HOST_ERROR_CATEGORY).is_would_block());
+///```
+pub static HOST_ERROR_CATEGORY: Category = Category {
+    name: "HostError",
+    message: host_message,
+    equivalent: host_equivalent,
+    is_would_block: host_is_would_block,
+    write_message: Some(host_write_message),
+};
+
+#[cfg(any(target_vendor = "apple", target_os = "freebsd", target_os = "dragonfly", target_os = "netbsd", target_os = "android", target_os = "solaris", target_os = "illumos", target_os = "haiku", target_os = "aix", target_os = "nto", target_os = "vxworks"))]
+mod consts {
+    use crate::types::c_int;
+
+    pub const EAI_AGAIN: c_int = 2;
+    pub const EAI_BADFLAGS: c_int = 3;
+    pub const EAI_FAIL: c_int = 4;
+    pub const EAI_FAMILY: c_int = 5;
+    pub const EAI_MEMORY: c_int = 6;
+    #[cfg(not(any(target_os = "freebsd", target_os = "dragonfly")))]
+    pub const EAI_NODATA: c_int = 7;
+    pub const EAI_NONAME: c_int = 8;
+    pub const EAI_SERVICE: c_int = 9;
+    pub const EAI_SOCKTYPE: c_int = 10;
+    pub const EAI_SYSTEM: c_int = 11;
+    #[cfg(any(target_os = "solaris", target_os = "illumos"))]
+    pub const EAI_OVERFLOW: c_int = 12;
+    #[cfg(target_os = "aix")]
+    pub const EAI_OVERFLOW: c_int = 13;
+    #[cfg(not(any(target_os = "solaris", target_os = "illumos", target_os = "aix")))]
+    pub const EAI_OVERFLOW: c_int = 14;
+
+    pub const HOST_NOT_FOUND: c_int = 1;
+    pub const TRY_AGAIN: c_int = 2;
+    pub const NO_RECOVERY: c_int = 3;
+    pub const NO_DATA: c_int = 4;
+}
+
+#[cfg(target_os = "openbsd")]
+mod consts {
+    use crate::types::c_int;
+
+    pub const EAI_BADFLAGS: c_int = -1;
+    pub const EAI_NONAME: c_int = -2;
+    pub const EAI_AGAIN: c_int = -3;
+    pub const EAI_FAIL: c_int = -4;
+    pub const EAI_NODATA: c_int = -5;
+    pub const EAI_FAMILY: c_int = -6;
+    pub const EAI_SOCKTYPE: c_int = -7;
+    pub const EAI_SERVICE: c_int = -8;
+    pub const EAI_MEMORY: c_int = -10;
+    pub const EAI_SYSTEM: c_int = -11;
+    pub const EAI_OVERFLOW: c_int = -14;
+
+    pub const HOST_NOT_FOUND: c_int = 1;
+    pub const TRY_AGAIN: c_int = 2;
+    pub const NO_RECOVERY: c_int = 3;
+    pub const NO_DATA: c_int = 4;
+}
+
+#[cfg(windows)]
+mod consts {
+    use crate::types::c_int;
+    use crate::win32;
+
+    pub const EAI_AGAIN: c_int = win32::WSATRY_AGAIN;
+    pub const EAI_BADFLAGS: c_int = win32::WSAEINVAL;
+    pub const EAI_FAIL: c_int = win32::WSANO_RECOVERY;
+    pub const EAI_FAMILY: c_int = win32::WSAEAFNOSUPPORT;
+    pub const EAI_MEMORY: c_int = win32::ERROR_NOT_ENOUGH_MEMORY;
+    pub const EAI_NODATA: c_int = EAI_NONAME;
+    pub const EAI_NONAME: c_int = win32::WSAHOST_NOT_FOUND;
+    pub const EAI_SERVICE: c_int = win32::WSATYPE_NOT_FOUND;
+    pub const EAI_SOCKTYPE: c_int = win32::WSAESOCKTNOSUPPORT;
+
+    pub const HOST_NOT_FOUND: c_int = win32::WSAHOST_NOT_FOUND;
+    pub const TRY_AGAIN: c_int = win32::WSATRY_AGAIN;
+    pub const NO_RECOVERY: c_int = win32::WSANO_RECOVERY;
+    pub const NO_DATA: c_int = win32::WSANO_DATA;
+}
+
+#[cfg(not(any(windows, target_os = "openbsd", target_vendor = "apple", target_os = "freebsd", target_os = "dragonfly", target_os = "netbsd", target_os = "android", target_os = "solaris", target_os = "illumos", target_os = "haiku", target_os = "aix", target_os = "nto", target_os = "vxworks")))]
+mod consts {
+    use crate::types::c_int;
+
+    pub const EAI_BADFLAGS: c_int = -1;
+    pub const EAI_NONAME: c_int = -2;
+    pub const EAI_AGAIN: c_int = -3;
+    pub const EAI_FAIL: c_int = -4;
+    pub const EAI_NODATA: c_int = -5;
+    pub const EAI_FAMILY: c_int = -6;
+    pub const EAI_SOCKTYPE: c_int = -7;
+    pub const EAI_SERVICE: c_int = -8;
+    pub const EAI_MEMORY: c_int = -10;
+    pub const EAI_SYSTEM: c_int = -11;
+    pub const EAI_OVERFLOW: c_int = -12;
+
+    pub const HOST_NOT_FOUND: c_int = 1;
+    pub const TRY_AGAIN: c_int = 2;
+    pub const NO_RECOVERY: c_int = 3;
+    pub const NO_DATA: c_int = 4;
+}
+
+///Temporary failure in name resolution.
+pub const EAI_AGAIN: c_int = consts::EAI_AGAIN;
+///Invalid value of `ai_flags`.
+pub const EAI_BADFLAGS: c_int = consts::EAI_BADFLAGS;
+///Non-recoverable failure in name resolution.
+pub const EAI_FAIL: c_int = consts::EAI_FAIL;
+///Address family is not supported.
+pub const EAI_FAMILY: c_int = consts::EAI_FAMILY;
+///Memory allocation failure.
+pub const EAI_MEMORY: c_int = consts::EAI_MEMORY;
+#[cfg(not(any(target_os = "freebsd", target_os = "dragonfly")))]
+///No address associated with host name.
+pub const EAI_NODATA: c_int = consts::EAI_NODATA;
+///Name or service is not known.
+pub const EAI_NONAME: c_int = consts::EAI_NONAME;
+///Service is not supported for socket type.
+pub const EAI_SERVICE: c_int = consts::EAI_SERVICE;
+///Socket type is not supported.
+pub const EAI_SOCKTYPE: c_int = consts::EAI_SOCKTYPE;
+#[cfg(not(windows))]
+///System error, actual error code is stored in `errno`.
+///
+///Not available on Windows, where resolver reports system errors directly.
+pub const EAI_SYSTEM: c_int = consts::EAI_SYSTEM;
+#[cfg(not(windows))]
+///Argument buffer overflow.
+///
+///Not available on Windows.
+pub const EAI_OVERFLOW: c_int = consts::EAI_OVERFLOW;
+
+///Authoritative answer: host is not found.
+pub const HOST_NOT_FOUND: c_int = consts::HOST_NOT_FOUND;
+///Non-authoritative answer: host is not found, or server failure.
+pub const TRY_AGAIN: c_int = consts::TRY_AGAIN;
+///Non-recoverable error.
+pub const NO_RECOVERY: c_int = consts::NO_RECOVERY;
+///Valid name, but no data record of requested type.
+pub const NO_DATA: c_int = consts::NO_DATA;
+///Alias of `NO_DATA`.
+pub const NO_ADDRESS: c_int = NO_DATA;
+
+const EAI_TABLE: &[(c_int, &str, &str)] = &[
+    (EAI_AGAIN, "EAI_AGAIN", "Temporary failure in name resolution"),
+    (EAI_BADFLAGS, "EAI_BADFLAGS", "Bad value for ai_flags"),
+    (EAI_FAIL, "EAI_FAIL", "Non-recoverable failure in name resolution"),
+    (EAI_FAMILY, "EAI_FAMILY", "ai_family not supported"),
+    (EAI_MEMORY, "EAI_MEMORY", "Memory allocation failure"),
+    (EAI_NONAME, "EAI_NONAME", "Name or service not known"),
+    #[cfg(not(any(windows, target_os = "freebsd", target_os = "dragonfly")))]
+    (EAI_NODATA, "EAI_NODATA", "No address associated with hostname"),
+    (EAI_SERVICE, "EAI_SERVICE", "Servname not supported for ai_socktype"),
+    (EAI_SOCKTYPE, "EAI_SOCKTYPE", "ai_socktype not supported"),
+    #[cfg(not(windows))]
+    (EAI_SYSTEM, "EAI_SYSTEM", "System error"),
+    #[cfg(not(windows))]
+    (EAI_OVERFLOW, "EAI_OVERFLOW", "Argument buffer overflow"),
+];
+
+const HOST_TABLE: &[(c_int, &str, &str)] = &[
+    (HOST_NOT_FOUND, "HOST_NOT_FOUND", "Unknown host"),
+    (TRY_AGAIN, "TRY_AGAIN", "Host name lookup failure"),
+    (NO_RECOVERY, "NO_RECOVERY", "Unknown server error"),
+    (NO_DATA, "NO_DATA", "No address associated with name"),
+];
+
+#[inline]
+fn lookup(table: &'static [(c_int, &'static str, &'static str)], code: c_int) -> Option<&'static (c_int, &'static str, &'static str)> {
+    table.iter().find(|entry| entry.0 == code)
+}
+
+#[inline]
+///Returns symbolic name of `EAI_*` error code, if it is known.
+pub fn name(code: c_int) -> Option<&'static str> {
+    lookup(EAI_TABLE, code).map(|entry| entry.1)
+}
+
+#[inline]
+///Returns English description of `EAI_*` error code, if it is known.
+pub fn description(code: c_int) -> Option<&'static str> {
+    lookup(EAI_TABLE, code).map(|entry| entry.2)
+}
+
+#[inline]
+///Returns symbolic name of `h_errno` error code, if it is known.
+pub fn host_name(code: c_int) -> Option<&'static str> {
+    lookup(HOST_TABLE, code).map(|entry| entry.1)
+}
+
+#[inline]
+///Returns English description of `h_errno` error code, if it is known.
+pub fn host_description(code: c_int) -> Option<&'static str> {
+    lookup(HOST_TABLE, code).map(|entry| entry.2)
+}
+
+///Creates error code from result of `getaddrinfo` or `getnameinfo`.
+///
+///When `code` is `EAI_SYSTEM`, actual error is stored in `errno`, hence this function returns
+///[last POSIX error](../struct.ErrorCode.html#method.last_posix) instead.
+///Therefore it must be called right after failed resolver function.
+pub fn gai_error(code: c_int) -> ErrorCode {
+    #[cfg(not(windows))]
+    if code == EAI_SYSTEM {
+        return ErrorCode::last_posix();
+    }
+
+    ErrorCode::new(code, &NETDB_CATEGORY)
+}
+
+#[cfg(unix)]
+///Retrieves description of the error code from C library and passes it to `cb`.
+fn with_gai_strerror<R>(code: c_int, cb: impl FnOnce(&[u8]) -> R) -> Option<R> {
+    extern "C" {
+        fn gai_strerror(code: c_int) -> *const i8;
+        fn strlen(text: *const i8) -> usize;
+    }
+
+    let err = unsafe {
+        gai_strerror(code)
+    };
+
+    if err.is_null() {
+        None
+    } else {
+        let err = unsafe {
+            core::slice::from_raw_parts(err as *const u8, strlen(err))
+        };
+        Some(cb(err))
+    }
+}
+
+fn equivalent(code: c_int, other: &ErrorCode) -> bool {
+    //On Windows resolver reports Win32 error codes
+    let other_category = other.category();
+    (ptr::eq(&NETDB_CATEGORY, other_category) || (cfg!(windows) && (ptr::eq(&crate::SYSTEM_CATEGORY, other_category) || ptr::eq(&crate::WIN32_CATEGORY, other_category)))) && code == other.raw_code()
+}
+
+fn is_would_block(code: c_int) -> bool {
+    code == EAI_AGAIN
+}
+
+fn message(code: c_int, out: &mut MessageBuf) -> &str {
+    let mut writer = MessageWriter::new(out);
+    let _ = write_message(code, &mut writer);
+    writer.finish()
+}
+
+fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    #[cfg(unix)]
+    {
+        if let Some(result) = with_gai_strerror(code, |err| crate::utils::write_utf8_lossy(out, err)) {
+            return result;
+        }
+    }
+
+    out.write_str(description(code).unwrap_or_else(|| generic_map_error_code(code)))
+}
+
+fn host_equivalent(code: c_int, other: &ErrorCode) -> bool {
+    ptr::eq(&HOST_ERROR_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+fn host_is_would_block(code: c_int) -> bool {
+    code == TRY_AGAIN
+}
+
+fn host_message(code: c_int, out: &mut MessageBuf) -> &str {
+    crate::utils::write_message_buf(out, host_description(code).unwrap_or_else(|| generic_map_error_code(code)))
+}
+
+fn host_write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    out.write_str(host_description(code).unwrap_or_else(|| generic_map_error_code(code)))
+}
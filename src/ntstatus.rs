@@ -0,0 +1,303 @@
+//!NTSTATUS error category
+//!
+//!Describes Windows kernel status codes using built-in table, hence it is available on every platform.
+use crate::{Category, ErrorCode, MessageBuf, WIN32_CATEGORY};
+use crate::utils::{MessageWriter, generic_map_error_code};
+use crate::types::c_int;
+use crate::win32;
+
+use core::{fmt, ptr};
+
+///NTSTATUS error category, describing Windows kernel status codes on any platform.
+///
+///Message includes hexadecimal representation of the code.
+///
+///## Usage
+///
+///```rust
+///use error_code::{ErrorCode, WIN32_CATEGORY};
+///use error_code::ntstatus::{self, NtStatus, Severity, NTSTATUS_CATEGORY};
+///
+///let status = NtStatus::new(ntstatus::STATUS_ACCESS_VIOLATION);
+///assert_eq!(status.severity(), Severity::Error);
+///assert_eq!(status.name(), Some("STATUS_ACCESS_VIOLATION"));
+///assert_eq!(status.to_win32(), ErrorCode::new(error_code::win32::ERROR_NOACCESS, &WIN32_CATEGORY));
+///
+///let error = ErrorCode::new(ntstatus::STATUS_ACCESS_VIOLATION, &NTSTATUS_CATEGORY);
+///assert_eq!(error.message(), "0xC0000005: The instruction referenced memory that could not be accessed");
+///```
+pub static NTSTATUS_CATEGORY: Category = Category {
+    name: "NtStatus",
+    message,
+    equivalent,
+    is_would_block,
+    write_message: Some(write_message),
+};
+
+macro_rules! define_ntstatus {
+    ($($name:ident = $code:literal, $message:literal $(=> $win32:expr)?,)+) => {
+        $(
+            #[doc = $message]
+            pub const $name: c_int = $code as u32 as c_int;
+        )+
+
+        const TABLE: &[(c_int, &str, &str)] = &[
+            $(
+                ($name, stringify!($name), $message),
+            )+
+        ];
+
+        const fn map_win32(code: c_int) -> Option<c_int> {
+            match code {
+                $($(
+                    $name => Some($win32),
+                )?)+
+                _ => None,
+            }
+        }
+    };
+}
+
+define_ntstatus! {
+    STATUS_SUCCESS = 0x00000000, "The operation completed successfully" => win32::ERROR_SUCCESS,
+    STATUS_TIMEOUT = 0x00000102, "The wait operation timed out" => win32::WAIT_TIMEOUT,
+    STATUS_PENDING = 0x00000103, "The operation that was requested is pending completion" => win32::ERROR_IO_PENDING,
+    STATUS_GUARD_PAGE_VIOLATION = 0x80000001, "Guard page was accessed",
+    STATUS_DATATYPE_MISALIGNMENT = 0x80000002, "A datatype misalignment was detected in a load or store instruction" => win32::ERROR_NOACCESS,
+    STATUS_BREAKPOINT = 0x80000003, "A breakpoint has been reached",
+    STATUS_SINGLE_STEP = 0x80000004, "A single step or trace operation has just been completed",
+    STATUS_BUFFER_OVERFLOW = 0x80000005, "The data was too large to fit into the specified buffer" => win32::ERROR_MORE_DATA,
+    STATUS_NO_MORE_FILES = 0x80000006, "No more files were found which match the file specification" => win32::ERROR_NO_MORE_FILES,
+    STATUS_NO_MORE_ENTRIES = 0x8000001A, "No more entries are available from an enumeration operation" => win32::ERROR_NO_MORE_ITEMS,
+    STATUS_UNSUCCESSFUL = 0xC0000001, "The requested operation was unsuccessful" => win32::ERROR_GEN_FAILURE,
+    STATUS_NOT_IMPLEMENTED = 0xC0000002, "The requested operation is not implemented" => win32::ERROR_INVALID_FUNCTION,
+    STATUS_INVALID_INFO_CLASS = 0xC0000003, "The specified information class is not a valid information class for the specified object" => win32::ERROR_INVALID_PARAMETER,
+    STATUS_INFO_LENGTH_MISMATCH = 0xC0000004, "The specified information record length does not match the length that is required for the specified information class" => win32::ERROR_BAD_LENGTH,
+    STATUS_ACCESS_VIOLATION = 0xC0000005, "The instruction referenced memory that could not be accessed" => win32::ERROR_NOACCESS,
+    STATUS_IN_PAGE_ERROR = 0xC0000006, "The required data was not placed into memory because of an I/O error" => win32::ERROR_SWAPERROR,
+    STATUS_INVALID_HANDLE = 0xC0000008, "An invalid HANDLE was specified" => win32::ERROR_INVALID_HANDLE,
+    STATUS_INVALID_PARAMETER = 0xC000000D, "An invalid parameter was passed to a service or function" => win32::ERROR_INVALID_PARAMETER,
+    STATUS_NO_SUCH_DEVICE = 0xC000000E, "A device that does not exist was specified" => win32::ERROR_FILE_NOT_FOUND,
+    STATUS_NO_SUCH_FILE = 0xC000000F, "The file does not exist" => win32::ERROR_FILE_NOT_FOUND,
+    STATUS_INVALID_DEVICE_REQUEST = 0xC0000010, "The specified request is not a valid operation for the target device" => win32::ERROR_INVALID_FUNCTION,
+    STATUS_END_OF_FILE = 0xC0000011, "The end-of-file marker has been reached" => win32::ERROR_HANDLE_EOF,
+    STATUS_NO_MEMORY = 0xC0000017, "Not enough virtual memory or paging file quota is available to complete the specified operation" => win32::ERROR_NOT_ENOUGH_MEMORY,
+    STATUS_CONFLICTING_ADDRESSES = 0xC0000018, "The specified address range conflicts with the address space" => win32::ERROR_INVALID_ADDRESS,
+    STATUS_INVALID_SYSTEM_SERVICE = 0xC000001C, "An invalid system service was specified in a system service call" => win32::ERROR_INVALID_FUNCTION,
+    STATUS_ILLEGAL_INSTRUCTION = 0xC000001D, "An attempt was made to execute an illegal instruction",
+    STATUS_ACCESS_DENIED = 0xC0000022, "A process has requested access to an object but has not been granted those access rights" => win32::ERROR_ACCESS_DENIED,
+    STATUS_BUFFER_TOO_SMALL = 0xC0000023, "The buffer is too small to contain the entry" => win32::ERROR_INSUFFICIENT_BUFFER,
+    STATUS_OBJECT_TYPE_MISMATCH = 0xC0000024, "There is a mismatch between the type of object that is required by the requested operation and the type of object that is specified in the request" => win32::ERROR_INVALID_HANDLE,
+    STATUS_NONCONTINUABLE_EXCEPTION = 0xC0000025, "The instruction caused a noncontinuable exception",
+    STATUS_INVALID_DISPOSITION = 0xC0000026, "An invalid exception disposition was returned by an exception handler",
+    STATUS_OBJECT_NAME_INVALID = 0xC0000033, "The object name is invalid" => win32::ERROR_INVALID_NAME,
+    STATUS_OBJECT_NAME_NOT_FOUND = 0xC0000034, "The object name is not found" => win32::ERROR_FILE_NOT_FOUND,
+    STATUS_OBJECT_NAME_COLLISION = 0xC0000035, "The object name already exists" => win32::ERROR_ALREADY_EXISTS,
+    STATUS_OBJECT_PATH_NOT_FOUND = 0xC000003A, "The path does not exist" => win32::ERROR_PATH_NOT_FOUND,
+    STATUS_OBJECT_PATH_SYNTAX_BAD = 0xC000003B, "The object path component was not a directory object" => win32::ERROR_BAD_PATHNAME,
+    STATUS_SHARING_VIOLATION = 0xC0000043, "A file cannot be opened because the share access flags are incompatible" => win32::ERROR_SHARING_VIOLATION,
+    STATUS_DELETE_PENDING = 0xC0000056, "A non-close operation has been requested of a file object that has a delete pending" => win32::ERROR_ACCESS_DENIED,
+    STATUS_PRIVILEGE_NOT_HELD = 0xC0000061, "A required privilege is not held by the client" => win32::ERROR_PRIVILEGE_NOT_HELD,
+    STATUS_LOGON_FAILURE = 0xC000006D, "The attempted logon is invalid" => win32::ERROR_LOGON_FAILURE,
+    STATUS_DISK_FULL = 0xC000007F, "An operation failed because the disk was full" => win32::ERROR_DISK_FULL,
+    STATUS_ARRAY_BOUNDS_EXCEEDED = 0xC000008C, "Array bounds exceeded",
+    STATUS_FLOAT_DENORMAL_OPERAND = 0xC000008D, "Floating-point denormal operand",
+    STATUS_FLOAT_DIVIDE_BY_ZERO = 0xC000008E, "Floating-point division by zero",
+    STATUS_FLOAT_INEXACT_RESULT = 0xC000008F, "Floating-point inexact result",
+    STATUS_FLOAT_INVALID_OPERATION = 0xC0000090, "Floating-point invalid operation",
+    STATUS_FLOAT_OVERFLOW = 0xC0000091, "Floating-point overflow",
+    STATUS_FLOAT_STACK_CHECK = 0xC0000092, "Floating-point stack check",
+    STATUS_FLOAT_UNDERFLOW = 0xC0000093, "Floating-point underflow",
+    STATUS_INTEGER_DIVIDE_BY_ZERO = 0xC0000094, "Integer division by zero",
+    STATUS_INTEGER_OVERFLOW = 0xC0000095, "Integer overflow" => win32::ERROR_ARITHMETIC_OVERFLOW,
+    STATUS_PRIVILEGED_INSTRUCTION = 0xC0000096, "Privileged instruction",
+    STATUS_INSUFFICIENT_RESOURCES = 0xC000009A, "Insufficient system resources exist to complete the API" => win32::ERROR_NO_SYSTEM_RESOURCES,
+    STATUS_IO_TIMEOUT = 0xC00000B5, "The specified I/O operation was not completed before the time-out period expired" => win32::ERROR_SEM_TIMEOUT,
+    STATUS_FILE_IS_A_DIRECTORY = 0xC00000BA, "The file that was specified as a target is a directory" => win32::ERROR_ACCESS_DENIED,
+    STATUS_NOT_SUPPORTED = 0xC00000BB, "The request is not supported" => win32::ERROR_NOT_SUPPORTED,
+    STATUS_STACK_OVERFLOW = 0xC00000FD, "A new guard page for the stack cannot be created" => win32::ERROR_STACK_OVERFLOW,
+    STATUS_DIRECTORY_NOT_EMPTY = 0xC0000101, "The directory is not empty" => win32::ERROR_DIR_NOT_EMPTY,
+    STATUS_NOT_A_DIRECTORY = 0xC0000103, "A requested opened file is not a directory" => win32::ERROR_DIRECTORY,
+    STATUS_CANCELLED = 0xC0000120, "The I/O request was canceled" => win32::ERROR_OPERATION_ABORTED,
+    STATUS_DLL_NOT_FOUND = 0xC0000135, "The code execution cannot proceed because required DLL was not found" => win32::ERROR_MOD_NOT_FOUND,
+    STATUS_ENTRYPOINT_NOT_FOUND = 0xC0000139, "The procedure entry point could not be located in the DLL" => win32::ERROR_PROC_NOT_FOUND,
+    STATUS_CONTROL_C_EXIT = 0xC000013A, "The application terminated as a result of a CTRL+C",
+    STATUS_DLL_INIT_FAILED = 0xC0000142, "The application failed to initialize, because initialization routine of DLL failed" => win32::ERROR_DLL_INIT_FAILED,
+    STATUS_PIPE_BROKEN = 0xC000014B, "The pipe operation has failed because the other end of the pipe has been closed" => win32::ERROR_BROKEN_PIPE,
+    STATUS_CONNECTION_RESET = 0xC000020D, "The transport connection was reset" => win32::ERROR_NETNAME_DELETED,
+    STATUS_NOT_FOUND = 0xC0000225, "The object was not found" => win32::ERROR_NOT_FOUND,
+    STATUS_CONNECTION_REFUSED = 0xC0000236, "The transport connection attempt was refused by the remote system" => win32::ERROR_CONNECTION_REFUSED,
+    STATUS_HEAP_CORRUPTION = 0xC0000374, "A heap has been corrupted",
+    STATUS_STACK_BUFFER_OVERRUN = 0xC0000409, "The system detected an overrun of a stack-based buffer in this application",
+    STATUS_INVALID_CRUNTIME_PARAMETER = 0xC0000417, "An invalid parameter was passed to a C runtime function",
+    STATUS_ASSERTION_FAILURE = 0xC0000420, "An assertion failure has occurred",
+}
+
+///Facility of Win32 error codes, wrapped into NTSTATUS.
+pub const FACILITY_NTWIN32: u16 = 7;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Severity of NTSTATUS.
+pub enum Severity {
+    ///Operation succeeded.
+    Success,
+    ///Operation succeeded with informational status.
+    Informational,
+    ///Operation completed with warning.
+    Warning,
+    ///Operation failed.
+    Error,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+///NTSTATUS, decomposed into its severity, facility and code.
+///
+///Displayed as hexadecimal number.
+pub struct NtStatus(c_int);
+
+impl NtStatus {
+    #[inline(always)]
+    ///Creates new instance from raw value.
+    pub const fn new(raw: c_int) -> Self {
+        Self(raw)
+    }
+
+    #[inline(always)]
+    ///Returns raw value.
+    pub const fn raw(self) -> c_int {
+        self.0
+    }
+
+    #[inline]
+    ///Returns severity.
+    pub const fn severity(self) -> Severity {
+        match self.0 as u32 >> 30 {
+            0 => Severity::Success,
+            1 => Severity::Informational,
+            2 => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns `true` if status indicates error, as `NT_ERROR`.
+    pub const fn is_error(self) -> bool {
+        self.0 as u32 >> 30 == 3
+    }
+
+    #[inline(always)]
+    ///Returns `true` if customer bit is set, indicating status is not defined by Microsoft.
+    pub const fn is_customer(self) -> bool {
+        self.0 as u32 & 0x2000_0000 != 0
+    }
+
+    #[inline(always)]
+    ///Returns facility.
+    pub const fn facility(self) -> u16 {
+        ((self.0 as u32 >> 16) & 0xFFF) as u16
+    }
+
+    #[inline(always)]
+    ///Returns facility specific code.
+    pub const fn code(self) -> u16 {
+        self.0 as u16
+    }
+
+    #[inline]
+    ///Returns symbolic name, if status is known.
+    pub fn name(self) -> Option<&'static str> {
+        TABLE.iter().find(|entry| entry.0 == self.0).map(|entry| entry.1)
+    }
+
+    ///Returns English description, if status is known.
+    ///
+    ///Statuses of `FACILITY_NTWIN32` are described as corresponding Win32 error codes.
+    pub fn description(self) -> Option<&'static str> {
+        match TABLE.iter().find(|entry| entry.0 == self.0) {
+            Some(entry) => Some(entry.2),
+            None if self.facility() == FACILITY_NTWIN32 => win32::description(self.code() as c_int),
+            None => None,
+        }
+    }
+
+    ///Maps status to Win32 error code, similarly to `RtlNtStatusToDosError`.
+    ///
+    ///Statuses of `FACILITY_NTWIN32` are mapped to wrapped code, while statuses without known
+    ///mapping are mapped to `ERROR_MR_MID_NOT_FOUND`.
+    ///
+    ///Returned error code belongs to [WIN32_CATEGORY](../static.WIN32_CATEGORY.html), which is equal
+    ///to `SYSTEM_CATEGORY` on Windows.
+    pub fn to_win32(self) -> ErrorCode {
+        let code = if self.facility() == FACILITY_NTWIN32 {
+            self.code() as c_int
+        } else {
+            match map_win32(self.0) {
+                Some(code) => code,
+                None => win32::ERROR_MR_MID_NOT_FOUND,
+            }
+        };
+        ErrorCode::new(code, &WIN32_CATEGORY)
+    }
+
+    #[inline(always)]
+    ///Converts into error code
+    pub fn into_error_code(self) -> ErrorCode {
+        ErrorCode::new(self.0, &NTSTATUS_CATEGORY)
+    }
+}
+
+impl From<NtStatus> for ErrorCode {
+    #[inline(always)]
+    fn from(this: NtStatus) -> Self {
+        this.into_error_code()
+    }
+}
+
+impl fmt::Debug for NtStatus {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("NtStatus({})", self))
+    }
+}
+
+impl fmt::Display for NtStatus {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("0x{:08X}", self.0))
+    }
+}
+
+impl fmt::LowerHex for NtStatus {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, fmt)
+    }
+}
+
+impl fmt::UpperHex for NtStatus {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, fmt)
+    }
+}
+
+fn equivalent(code: c_int, other: &ErrorCode) -> bool {
+    ptr::eq(&NTSTATUS_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+fn is_would_block(code: c_int) -> bool {
+    code == STATUS_PENDING
+}
+
+fn message(code: c_int, out: &mut MessageBuf) -> &str {
+    let mut writer = MessageWriter::new(out);
+    let _ = write_message(code, &mut writer);
+    writer.finish()
+}
+
+fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    let status = NtStatus::new(code);
+    out.write_fmt(format_args!("{}: ", status))?;
+    out.write_str(status.description().unwrap_or_else(|| generic_map_error_code(if status.is_error() { code } else { 0 })))
+}
@@ -1,8 +1,9 @@
+//!POSIX error category
 use crate::{Category, MessageBuf, ErrorCode};
-use crate::utils::write_fallback_code;
+use crate::utils::{MessageWriter, generic_map_error_code};
 use crate::types::c_int;
 
-use core::{ptr, str};
+use core::{fmt, ptr};
 
 /// Posix error category, suitable for all environments.
 ///
@@ -12,13 +13,14 @@ pub static POSIX_CATEGORY: Category = Category {
     message,
     equivalent,
     is_would_block,
+    write_message: Some(write_message),
 };
 
 fn equivalent(code: c_int, other: &ErrorCode) -> bool {
     ptr::eq(&POSIX_CATEGORY, other.category()) && code == other.raw_code()
 }
 
-#[cfg(not(any(target_os = "cloudabi", target_os = "unknown")))]
+#[cfg(not(target_os = "unknown"))]
 pub(crate) fn get_last_error() -> c_int {
     //Reference:
     //https://github.com/rust-lang/rust/blob/2ae1bb671183a072b54ed8ed39abfcd72990a3e7/library/std/src/sys/pal/unix/os.rs#L42
@@ -69,7 +71,7 @@ pub(crate) fn get_last_error() -> c_int {
     }
 }
 
-#[cfg(any(target_os = "cloudabi", target_os = "dragonfly"))]
+#[cfg(target_os = "dragonfly")]
 pub(crate) fn get_last_error() -> c_int {
     //WASI implements it as thread local, but thread local are not stable :(
     extern "C" {
@@ -96,7 +98,18 @@ pub(crate) fn get_last_error() -> c_int {
     0
 }
 
-pub(crate) fn message(_code: c_int, out: &mut MessageBuf) -> &str {
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Locale in which to describe error code.
+pub enum Locale {
+    ///Locale of the current thread.
+    Current,
+    ///`C` locale, describing error in English regardless of process's configuration.
+    C,
+}
+
+#[cfg(any(windows, unix, target_os = "wasi"))]
+///Retrieves description of the error code from C library and passes it to `cb`.
+fn with_strerror<R>(code: c_int, _locale: Locale, cb: impl FnOnce(&[u8]) -> R) -> Option<R> {
     #[cfg(any(windows, target_os = "wasi", all(unix, not(target_env = "gnu"))))]
     extern "C" {
         ///Only GNU impl is thread unsafe
@@ -108,37 +121,76 @@ pub(crate) fn message(_code: c_int, out: &mut MessageBuf) -> &str {
     extern "C" {
         fn strerror_l(code: c_int, locale: *mut i8) -> *const i8;
         fn strlen(text: *const i8) -> usize;
+        fn newlocale(mask: c_int, locale: *const i8, base: *mut i8) -> *mut i8;
+        fn freelocale(locale: *mut i8);
     }
 
+    //Only GNU libc translates error messages, other implementations always use English.
     #[cfg(all(unix, target_env = "gnu"))]
-    #[inline]
-    unsafe fn strerror(code: c_int) -> *const i8 {
-        strerror_l(code, ptr::null_mut())
-    }
+    let locale = match _locale {
+        //Null locale makes strerror_l to use locale of the current thread
+        Locale::Current => ptr::null_mut(),
+        Locale::C => {
+            const LC_ALL_MASK: c_int = 0x1fbf;
+            unsafe {
+                newlocale(LC_ALL_MASK, b"C\0".as_ptr() as *const i8, ptr::null_mut())
+            }
+        }
+    };
 
-    #[cfg(any(windows, unix, target_os = "wasi"))]
-    {
+    #[cfg(all(unix, target_env = "gnu"))]
+    let err = unsafe {
+        strerror_l(code, locale)
+    };
+    #[cfg(not(all(unix, target_env = "gnu")))]
+    let err = unsafe {
+        strerror(code)
+    };
+
+    let result = if err.is_null() {
+        None
+    } else {
         let err = unsafe {
-            strerror(_code)
+            core::slice::from_raw_parts(err as *const u8, strlen(err))
         };
+        Some(cb(err))
+    };
 
-        if !err.is_null() {
-            let err_len = unsafe {
-                core::cmp::min(out.len(), strlen(err) as usize)
-            };
+    #[cfg(all(unix, target_env = "gnu"))]
+    if !locale.is_null() {
+        unsafe {
+            freelocale(locale)
+        }
+    }
 
-            let err_slice = unsafe {
-                ptr::copy_nonoverlapping(err as *const u8, out.as_mut_ptr() as *mut u8, err_len);
-                core::slice::from_raw_parts(out.as_ptr() as *const u8, err_len)
-            };
+    result
+}
 
-            if let Ok(msg) = str::from_utf8(err_slice) {
-                return msg
-            }
+pub(crate) fn message(code: c_int, out: &mut MessageBuf) -> &str {
+    let mut writer = MessageWriter::new(out);
+    let _ = write_message_in(code, Locale::Current, &mut writer);
+    writer.finish()
+}
+
+pub(crate) fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    write_message_in(code, Locale::Current, out)
+}
+
+///Writes description of POSIX error code in the specified `locale`.
+///
+///If C library describes error using encoding other than UTF-8, invalid sequences are replaced
+///with `U+FFFD REPLACEMENT CHARACTER`.
+///
+///Only GNU libc localizes error descriptions, hence `locale` has no effect on other platforms.
+pub fn write_message_in(_code: c_int, _locale: Locale, out: &mut dyn fmt::Write) -> fmt::Result {
+    #[cfg(any(windows, unix, target_os = "wasi"))]
+    {
+        if let Some(result) = with_strerror(_code, _locale, |err| crate::utils::write_utf8_lossy(out, err)) {
+            return result;
         }
     }
 
-    write_fallback_code(out, _code)
+    out.write_str(generic_map_error_code(_code))
 }
 
 #[cfg(not(any(windows, unix, target_os = "wasi")))]
@@ -150,3 +202,366 @@ pub(crate) fn is_would_block(_: c_int) -> bool {
 pub(crate) fn is_would_block(code: c_int) -> bool {
     code == crate::defs::EWOULDBLOCK || code == crate::defs::EAGAIN
 }
+
+macro_rules! platform_value {
+    ($platform:expr, $name:ident) => {
+        match $platform {
+            Platform::Linux => crate::defs::linux::value::$name,
+            Platform::LinuxMips => crate::defs::linux_mips::value::$name,
+            Platform::LinuxSparc => crate::defs::linux_sparc::value::$name,
+            Platform::LinuxPowerPc => crate::defs::linux_powerpc::value::$name,
+            Platform::Apple => crate::defs::apple::value::$name,
+            Platform::FreeBsd => crate::defs::freebsd::value::$name,
+            Platform::DragonFly => crate::defs::dragonfly::value::$name,
+            Platform::OpenBsd => crate::defs::openbsd::value::$name,
+            Platform::NetBsd => crate::defs::netbsd::value::$name,
+            Platform::Solarish => crate::defs::solarish::value::$name,
+            Platform::Aix => crate::defs::aix::value::$name,
+            Platform::Nto => crate::defs::nto::value::$name,
+            Platform::Newlib => crate::defs::newlib::value::$name,
+            #[cfg(not(any(target_arch = "avr", target_arch = "msp430")))]
+            Platform::Haiku => crate::defs::haiku::value::$name,
+            Platform::Redox => crate::defs::redox::value::$name,
+            Platform::Wasi => crate::defs::wasi::value::$name,
+            Platform::Emscripten => crate::defs::emscripten::value::$name,
+            Platform::Windows => crate::defs::windows::value::$name,
+            Platform::VxWorks => crate::defs::vxworks::value::$name,
+            Platform::Solid => crate::defs::solid::value::$name,
+        }
+    };
+}
+
+macro_rules! define_errno {
+    ($($(#[$meta:meta])* $name:ident => $desc:literal,)+) => {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        ///Standard POSIX error code.
+        ///
+        ///Raw value of each variant corresponds to constant of the same name within [defs](../defs/index.html).
+        ///
+        ///When two codes share the same value on target platform (e.g. `EAGAIN` and `EWOULDBLOCK`),
+        ///raw value is converted into the first one in alphabetical order.
+        ///
+        ///Codes, that are not defined by target platform (e.g. `ESTALE` on Windows), are not available.
+        ///
+        ///## Usage
+        ///
+        ///```rust
+        ///use error_code::ErrorCode;
+        ///use error_code::posix::Errno;
+        ///
+        ///let error = ErrorCode::from(Errno::ENOENT);
+        ///assert_eq!(error.as_errno(), Some(Errno::ENOENT));
+        ///assert_eq!(Errno::from_raw(error.raw_code()), Some(Errno::ENOENT));
+        ///assert_eq!(Errno::ENOENT.name(), "ENOENT");
+        ///```
+        pub enum Errno {
+            $(
+                $(#[$meta])*
+                #[doc = $desc]
+                $name,
+            )+
+        }
+
+        impl Errno {
+            #[inline]
+            ///Returns raw error code on target platform.
+            pub const fn raw(self) -> c_int {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Errno::$name => crate::defs::$name,
+                    )+
+                }
+            }
+
+            #[inline]
+            #[allow(unreachable_patterns)]
+            ///Converts raw error code of target platform, returning `None` if code is not known.
+            pub const fn from_raw(code: c_int) -> Option<Self> {
+                match code {
+                    $(
+                        $(#[$meta])*
+                        crate::defs::$name => Some(Errno::$name),
+                    )+
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            ///Returns name of the error code's constant.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Errno::$name => stringify!($name),
+                    )+
+                }
+            }
+
+            ///Returns raw error code on specified `platform`, if it is defined there.
+            pub const fn raw_in(self, platform: Platform) -> Option<c_int> {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Errno::$name => platform_value!(platform, $name),
+                    )+
+                }
+            }
+
+            ///Converts raw error code of specified `platform`, returning `None` if code is not known.
+            pub const fn from_raw_in(code: c_int, platform: Platform) -> Option<Self> {
+                $(
+                    $(#[$meta])*
+                    if let Some(value) = platform_value!(platform, $name) {
+                        if value == code {
+                            return Some(Errno::$name);
+                        }
+                    }
+                )+
+                None
+            }
+        }
+    };
+}
+
+define_errno! {
+    E2BIG => "Argument list too long",
+    EACCES => "Permission denied",
+    EADDRINUSE => "Address in use",
+    EADDRNOTAVAIL => "Address not available",
+    EAFNOSUPPORT => "Address family not supported",
+    EAGAIN => "Resource temporarily unavailable",
+    EALREADY => "Connection already in progress",
+    EBADF => "Bad file descriptor",
+    EBADMSG => "Bad message",
+    EBUSY => "Device or resource busy",
+    ECANCELED => "Operation canceled",
+    ECHILD => "No child processes",
+    ECONNABORTED => "Connection aborted",
+    ECONNREFUSED => "Connection refused",
+    ECONNRESET => "Connection reset",
+    EDEADLK => "Resource deadlock avoided",
+    EDESTADDRREQ => "Destination address required",
+    EDOM => "Mathematics argument out of domain of function",
+    #[cfg(not(windows))]
+    EDQUOT => "Disk quota exceeded",
+    EEXIST => "File exists",
+    EFAULT => "Bad address",
+    EFBIG => "File too large",
+    EHOSTUNREACH => "Host is unreachable",
+    EIDRM => "Identifier removed",
+    EILSEQ => "Illegal byte sequence",
+    EINPROGRESS => "Operation in progress",
+    EINTR => "Interrupted function call",
+    EINVAL => "Invalid argument",
+    EIO => "Input/output error",
+    EISCONN => "Socket is connected",
+    EISDIR => "Is a directory",
+    ELOOP => "Too many levels of symbolic links",
+    EMFILE => "Too many open files",
+    EMLINK => "Too many links",
+    EMSGSIZE => "Message too long",
+    #[cfg(not(any(windows, target_os = "openbsd")))]
+    EMULTIHOP => "Multihop attempted",
+    ENAMETOOLONG => "Filename too long",
+    ENETDOWN => "Network is down",
+    ENETRESET => "Connection aborted by network",
+    ENETUNREACH => "Network unreachable",
+    ENFILE => "Too many open files in system",
+    ENOBUFS => "No buffer space available",
+    #[cfg(not(any(target_os = "freebsd", target_os = "dragonfly", target_os = "openbsd", target_os = "wasi")))]
+    ENODATA => "No data available",
+    ENODEV => "No such device",
+    ENOENT => "No such file or directory",
+    ENOEXEC => "Exec format error",
+    ENOLCK => "No locks available",
+    #[cfg(not(target_os = "openbsd"))]
+    ENOLINK => "Link has been severed",
+    ENOMEM => "Not enough space",
+    ENOMSG => "No message of the desired type",
+    ENOPROTOOPT => "Protocol not available",
+    ENOSPC => "No space left on device",
+    #[cfg(not(any(target_os = "freebsd", target_os = "dragonfly", target_os = "openbsd", target_os = "wasi")))]
+    ENOSR => "No STREAM resources",
+    #[cfg(not(any(target_os = "freebsd", target_os = "dragonfly", target_os = "openbsd", target_os = "wasi")))]
+    ENOSTR => "Not a STREAM",
+    ENOSYS => "Function not implemented",
+    ENOTCONN => "The socket is not connected",
+    ENOTDIR => "Not a directory",
+    ENOTEMPTY => "Directory not empty",
+    #[cfg(not(target_os = "haiku"))]
+    ENOTRECOVERABLE => "State not recoverable",
+    ENOTSOCK => "Not a socket",
+    ENOTSUP => "Operation not supported",
+    ENOTTY => "Inappropriate I/O control operation",
+    ENXIO => "No such device or address",
+    EOPNOTSUPP => "Operation not supported on socket",
+    EOVERFLOW => "Value too large to be stored in data type",
+    #[cfg(not(target_os = "haiku"))]
+    EOWNERDEAD => "Previous owner died",
+    EPERM => "Operation not permitted",
+    EPIPE => "Broken pipe",
+    EPROTO => "Protocol error",
+    EPROTONOSUPPORT => "Protocol not supported",
+    EPROTOTYPE => "Protocol wrong type for socket",
+    ERANGE => "Result too large",
+    EROFS => "Read-only file system",
+    ESPIPE => "Invalid seek",
+    ESRCH => "No such process",
+    #[cfg(not(windows))]
+    ESTALE => "Stale file handle",
+    #[cfg(not(any(target_os = "freebsd", target_os = "dragonfly", target_os = "openbsd", target_os = "wasi")))]
+    ETIME => "Timer expired",
+    ETIMEDOUT => "Connection timed out",
+    ETXTBSY => "Text file busy",
+    EWOULDBLOCK => "Operation would block",
+    EXDEV => "Improper link",
+}
+
+impl From<Errno> for ErrorCode {
+    #[inline(always)]
+    fn from(errno: Errno) -> Self {
+        ErrorCode::new_posix(errno.raw())
+    }
+}
+
+impl fmt::Display for Errno {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&ErrorCode::from(*self), fmt)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+///Platform, which defines numbering of POSIX error codes.
+///
+///Error codes of each platform are available within corresponding sub-module of [defs](../defs/index.html).
+pub enum Platform {
+    ///Linux, Android, Fuchsia, L4Re and TEEOS.
+    ///
+    ///This numbering is considered portable.
+    Linux,
+    ///Linux on MIPS architecture.
+    LinuxMips,
+    ///Linux on SPARC architecture.
+    LinuxSparc,
+    ///Linux on PowerPC architecture.
+    LinuxPowerPc,
+    ///macOS, iOS, tvOS, watchOS and visionOS.
+    Apple,
+    ///FreeBSD.
+    FreeBsd,
+    ///DragonFly BSD.
+    DragonFly,
+    ///OpenBSD.
+    OpenBsd,
+    ///NetBSD.
+    NetBsd,
+    ///Solaris and illumos.
+    Solarish,
+    ///AIX.
+    Aix,
+    ///QNX Neutrino.
+    Nto,
+    ///Environments based on newlib.
+    Newlib,
+    ///Haiku.
+    ///
+    ///Not available on targets with 16-bit `c_int`.
+    #[cfg(not(any(target_arch = "avr", target_arch = "msp430")))]
+    Haiku,
+    ///Redox.
+    Redox,
+    ///WASI.
+    Wasi,
+    ///Emscripten.
+    Emscripten,
+    ///Windows C runtime.
+    Windows,
+    ///VxWorks.
+    VxWorks,
+    ///SOLID.
+    Solid,
+}
+
+impl Platform {
+    ///Platform of the current target.
+    pub const CURRENT: Platform = crate::defs::PLATFORM;
+}
+
+#[inline]
+///Translates POSIX error code from numbering of one platform to another.
+///
+///Returns `None` if `code` is not known on `from` platform, or it is not defined on `to` platform.
+///
+///## Usage
+///
+///```rust
+///use error_code::defs;
+///use error_code::posix::{translate, Platform};
+///
+///assert_eq!(translate(defs::apple::EAGAIN, Platform::Apple, Platform::Linux), Some(defs::linux::EAGAIN));
+///assert_eq!(translate(defs::windows::EWOULDBLOCK, Platform::Windows, Platform::Wasi), Some(defs::wasi::EWOULDBLOCK));
+///assert_eq!(translate(0, Platform::Linux, Platform::Windows), None);
+///```
+pub const fn translate(code: c_int, from: Platform, to: Platform) -> Option<c_int> {
+    match Errno::from_raw_in(code, from) {
+        Some(errno) => errno.raw_in(to),
+        None => None,
+    }
+}
+
+#[inline]
+///Replaces error code, that is alias of another one on target platform, with the canonical one.
+///
+///Following aliases are replaced:
+///
+///- `EWOULDBLOCK` with `EAGAIN`;
+///- `EOPNOTSUPP` with `ENOTSUP`;
+///- `EDEADLOCK` with `EDEADLK`, where defined.
+///
+///## Usage
+///
+///```rust
+///use error_code::defs;
+///use error_code::posix::normalize;
+///
+///assert_eq!(normalize(defs::EWOULDBLOCK), defs::EAGAIN);
+///assert_eq!(normalize(defs::EOPNOTSUPP), defs::ENOTSUP);
+///assert_eq!(normalize(defs::ENOENT), defs::ENOENT);
+///```
+pub const fn normalize(code: c_int) -> c_int {
+    normalize_in(code, Platform::CURRENT)
+}
+
+#[inline]
+///Replaces error code, that is alias of another one on specified `platform`, with the canonical one.
+///
+///Refer to [normalize](fn.normalize.html) for list of aliases.
+pub const fn normalize_in(code: c_int, platform: Platform) -> c_int {
+    match platform {
+        Platform::Linux => crate::defs::linux::normalize(code),
+        Platform::LinuxMips => crate::defs::linux_mips::normalize(code),
+        Platform::LinuxSparc => crate::defs::linux_sparc::normalize(code),
+        Platform::LinuxPowerPc => crate::defs::linux_powerpc::normalize(code),
+        Platform::Apple => crate::defs::apple::normalize(code),
+        Platform::FreeBsd => crate::defs::freebsd::normalize(code),
+        Platform::DragonFly => crate::defs::dragonfly::normalize(code),
+        Platform::OpenBsd => crate::defs::openbsd::normalize(code),
+        Platform::NetBsd => crate::defs::netbsd::normalize(code),
+        Platform::Solarish => crate::defs::solarish::normalize(code),
+        Platform::Aix => crate::defs::aix::normalize(code),
+        Platform::Nto => crate::defs::nto::normalize(code),
+        Platform::Newlib => crate::defs::newlib::normalize(code),
+        #[cfg(not(any(target_arch = "avr", target_arch = "msp430")))]
+        Platform::Haiku => crate::defs::haiku::normalize(code),
+        Platform::Redox => crate::defs::redox::normalize(code),
+        Platform::Wasi => crate::defs::wasi::normalize(code),
+        Platform::Emscripten => crate::defs::emscripten::normalize(code),
+        Platform::Windows => crate::defs::windows::normalize(code),
+        Platform::VxWorks => crate::defs::vxworks::normalize(code),
+        Platform::Solid => crate::defs::solid::normalize(code),
+    }
+}
@@ -0,0 +1,259 @@
+//!Process termination support
+//!
+//!Allows `main` to return error code, exiting with meaningful status.
+use crate::{ErrorCode, ContextError, EXIT_CATEGORY, SIGNAL_CATEGORY, SYSTEM_CATEGORY, WIN32_CATEGORY};
+use crate::posix::Errno;
+use crate::{exit, win32};
+
+use core::fmt;
+use std::process::{ExitCode, Termination};
+
+///Table mapping POSIX error codes to exit codes.
+pub type ExitTable = &'static [(Errno, u8)];
+
+///Exit code used when error cannot be mapped to more specific one.
+pub const EXIT_FAILURE: u8 = 1;
+
+///Default mapping of POSIX error codes to `sysexits.h` exit codes.
+pub const DEFAULT_EXIT_TABLE: ExitTable = &[
+    (Errno::E2BIG, exit::EX_USAGE as u8),
+    (Errno::EINVAL, exit::EX_DATAERR as u8),
+    (Errno::EILSEQ, exit::EX_DATAERR as u8),
+    (Errno::ENOENT, exit::EX_NOINPUT as u8),
+    (Errno::ENOTDIR, exit::EX_NOINPUT as u8),
+    (Errno::EISDIR, exit::EX_NOINPUT as u8),
+    (Errno::ECONNREFUSED, exit::EX_UNAVAILABLE as u8),
+    (Errno::EHOSTUNREACH, exit::EX_UNAVAILABLE as u8),
+    (Errno::ENETUNREACH, exit::EX_UNAVAILABLE as u8),
+    (Errno::ENETDOWN, exit::EX_UNAVAILABLE as u8),
+    (Errno::ENOSYS, exit::EX_UNAVAILABLE as u8),
+    (Errno::ENOMEM, exit::EX_OSERR as u8),
+    (Errno::EMFILE, exit::EX_OSERR as u8),
+    (Errno::ENFILE, exit::EX_OSERR as u8),
+    (Errno::EEXIST, exit::EX_CANTCREAT as u8),
+    (Errno::EROFS, exit::EX_CANTCREAT as u8),
+    (Errno::EIO, exit::EX_IOERR as u8),
+    (Errno::ENOSPC, exit::EX_IOERR as u8),
+    (Errno::EPIPE, exit::EX_IOERR as u8),
+    (Errno::EAGAIN, exit::EX_TEMPFAIL as u8),
+    (Errno::EBUSY, exit::EX_TEMPFAIL as u8),
+    (Errno::EINTR, exit::EX_TEMPFAIL as u8),
+    (Errno::ETIMEDOUT, exit::EX_TEMPFAIL as u8),
+    (Errno::EPROTO, exit::EX_PROTOCOL as u8),
+    (Errno::EBADMSG, exit::EX_PROTOCOL as u8),
+    (Errno::EPERM, exit::EX_NOPERM as u8),
+    (Errno::EACCES, exit::EX_NOPERM as u8),
+];
+
+#[derive(Copy, Clone)]
+///Error to terminate process with, describing error code and optional context.
+///
+///On termination, error is printed to stderr, and process exits with code determined as following:
+///
+///- `POSIX` error codes are mapped using table, which is [DEFAULT_EXIT_TABLE](constant.DEFAULT_EXIT_TABLE.html) unless configured otherwise;
+///- Error codes of [EXIT_CATEGORY](../static.EXIT_CATEGORY.html) are used as it is;
+///- Signals are mapped to `128 + signal`, following shell convention;
+///- Anything else exits with [EXIT_FAILURE](constant.EXIT_FAILURE.html).
+///
+///Win32 error codes, including system error codes on Windows, are converted to POSIX ones using
+///[win32::try_to_errno](../win32/fn.try_to_errno.html), exiting with [EXIT_FAILURE](constant.EXIT_FAILURE.html)
+///if there is no corresponding POSIX error code.
+///
+///## Usage
+///
+///```rust
+///use error_code::{defs, ErrorCode, ResultExt};
+///use error_code::report::ExitError;
+///
+///let error = ExitError::from(Err::<(), _>(ErrorCode::new_posix(defs::ENOENT)).context("Opening config").unwrap_err());
+///assert_eq!(error.exit_code(), 66);
+///assert_eq!(error.to_string(), format!("Opening config: {}", ErrorCode::new_posix(defs::ENOENT)));
+///```
+pub struct ExitError {
+    context: Option<&'static str>,
+    code: ErrorCode,
+    table: ExitTable,
+}
+
+impl ExitError {
+    #[inline]
+    ///Creates new error, using default table.
+    pub const fn new(code: ErrorCode) -> Self {
+        Self {
+            context: None,
+            code,
+            table: DEFAULT_EXIT_TABLE,
+        }
+    }
+
+    #[inline]
+    ///Attaches `context` to the error.
+    pub const fn with_context(mut self, context: &'static str) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    #[inline]
+    ///Sets table to map POSIX error codes with.
+    ///
+    ///Codes absent from `table` are mapped to [EXIT_FAILURE](constant.EXIT_FAILURE.html).
+    pub const fn with_table(mut self, table: ExitTable) -> Self {
+        self.table = table;
+        self
+    }
+
+    #[inline]
+    ///Gets context of the error, if any.
+    pub const fn context(&self) -> Option<&'static str> {
+        self.context
+    }
+
+    #[inline]
+    ///Gets underlying error code.
+    pub const fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    ///Determines exit code to terminate process with.
+    pub fn exit_code(&self) -> u8 {
+        let code = self.code.raw_code();
+        if self.code.is_category(&EXIT_CATEGORY) {
+            return match code {
+                1..=255 => code as u8,
+                _ => EXIT_FAILURE,
+            };
+        } else if self.code.is_category(&SIGNAL_CATEGORY) {
+            return match code {
+                1..=127 => 128 + code as u8,
+                _ => EXIT_FAILURE,
+            };
+        }
+
+        let errno = match self.code.as_errno() {
+            Some(errno) => Some(errno),
+            None if self.code.is_category(&WIN32_CATEGORY) || (cfg!(windows) && self.code.is_category(&SYSTEM_CATEGORY)) => win32::try_to_errno(code),
+            None => None,
+        };
+
+        match errno {
+            Some(errno) => self.table.iter().find(|entry| entry.0.raw() == errno.raw()).map(|entry| entry.1).unwrap_or(EXIT_FAILURE),
+            None => EXIT_FAILURE,
+        }
+    }
+}
+
+impl From<ErrorCode> for ExitError {
+    #[inline(always)]
+    fn from(code: ErrorCode) -> Self {
+        Self::new(code)
+    }
+}
+
+impl From<ContextError> for ExitError {
+    #[inline(always)]
+    fn from(error: ContextError) -> Self {
+        Self::new(error.code()).with_context(error.context())
+    }
+}
+
+impl From<std::io::Error> for ExitError {
+    #[inline]
+    fn from(error: std::io::Error) -> Self {
+        //OS error is errno on Unix, but Win32 error code on Windows
+        match error.raw_os_error() {
+            Some(code) => Self::new(ErrorCode::new_system(code)),
+            None => Self::new(error.into()),
+        }
+    }
+}
+
+impl fmt::Debug for ExitError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ExitError").field("context", &self.context).field("code", &self.code).finish()
+    }
+}
+
+impl fmt::Display for ExitError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.context {
+            Some(context) => fmt.write_fmt(format_args!("{}: {}", context, self.code)),
+            None => fmt::Display::fmt(&self.code, fmt),
+        }
+    }
+}
+
+impl std::error::Error for ExitError {}
+
+impl Termination for ExitError {
+    fn report(self) -> ExitCode {
+        std::eprintln!("Error: {}", self);
+        ExitCode::from(self.exit_code())
+    }
+}
+
+///Result of `main`, terminating process with meaningful exit code on error.
+///
+///Use [ExitError](struct.ExitError.html) to configure mapping of error codes.
+///
+///## Usage
+///
+///```rust,no_run
+///use error_code::{ErrorCode, ResultExt};
+///use error_code::report::Report;
+///
+///fn run() -> Result<(), ErrorCode> {
+///    std::fs::File::open("config.toml")?;
+///    Ok(())
+///}
+///
+///fn main() -> Report {
+///    run().context("Opening config").into()
+///}
+///```
+pub struct Report(Result<(), ExitError>);
+
+impl Report {
+    #[inline]
+    ///Creates new instance from result.
+    pub fn new<E: Into<ExitError>>(result: Result<(), E>) -> Self {
+        Self(result.map_err(Into::into))
+    }
+
+    #[inline]
+    ///Sets table to map POSIX error codes with.
+    pub fn with_table(self, table: ExitTable) -> Self {
+        Self(self.0.map_err(|error| error.with_table(table)))
+    }
+
+    #[inline]
+    ///Returns underlying result.
+    pub fn into_result(self) -> Result<(), ExitError> {
+        self.0
+    }
+}
+
+impl<E: Into<ExitError>> From<Result<(), E>> for Report {
+    #[inline(always)]
+    fn from(result: Result<(), E>) -> Self {
+        Self::new(result)
+    }
+}
+
+impl fmt::Debug for Report {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("Report").field(&self.0).finish()
+    }
+}
+
+impl Termination for Report {
+    #[inline]
+    fn report(self) -> ExitCode {
+        match self.0 {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => error.report(),
+        }
+    }
+}
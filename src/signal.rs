@@ -0,0 +1,386 @@
+//!Signal category
+//!
+//!Describes signal numbers, allowing to report termination of process by signal as error code.
+//!
+//!Constants correspond to numbering of target platform.
+//!Unknown targets use Linux numbering.
+use crate::{Category, ErrorCode, MessageBuf};
+use crate::utils::{MessageWriter, generic_map_error_code};
+use crate::types::c_int;
+
+use core::{fmt, ptr};
+
+///Signal category, describing signal numbers.
+///
+///On hosted Unix messages are taken from `strsignal`, otherwise built-in table of English
+///descriptions is used.
+///
+///Names are always taken from built-in table, as `sigabbrev_np` is only available in recent GNU libc.
+///
+///## Usage
+///
+///```rust
+///use error_code::{ErrorCode, SIGNAL_CATEGORY};
+///use error_code::signal;
+///
+///let error = ErrorCode::new(signal::SIGSEGV, &SIGNAL_CATEGORY);
+///assert_eq!(signal::name(error.raw_code()), Some("SIGSEGV"));
+///assert_eq!(signal::description(error.raw_code()), Some("Segmentation fault"));
+///```
+pub static SIGNAL_CATEGORY: Category = Category {
+    name: "Signal",
+    message,
+    equivalent,
+    is_would_block,
+    write_message: Some(write_message),
+};
+
+macro_rules! description {
+    (SIGHUP) => { "Hangup" };
+    (SIGINT) => { "Interrupt" };
+    (SIGQUIT) => { "Quit" };
+    (SIGILL) => { "Illegal instruction" };
+    (SIGTRAP) => { "Trace/breakpoint trap" };
+    (SIGABRT) => { "Aborted" };
+    (SIGEMT) => { "EMT trap" };
+    (SIGBUS) => { "Bus error" };
+    (SIGFPE) => { "Floating point exception" };
+    (SIGKILL) => { "Killed" };
+    (SIGUSR1) => { "User defined signal 1" };
+    (SIGSEGV) => { "Segmentation fault" };
+    (SIGUSR2) => { "User defined signal 2" };
+    (SIGPIPE) => { "Broken pipe" };
+    (SIGALRM) => { "Alarm clock" };
+    (SIGTERM) => { "Terminated" };
+    (SIGSTKFLT) => { "Stack fault" };
+    (SIGCHLD) => { "Child exited" };
+    (SIGCONT) => { "Continued" };
+    (SIGSTOP) => { "Stopped (signal)" };
+    (SIGTSTP) => { "Stopped" };
+    (SIGTTIN) => { "Stopped (tty input)" };
+    (SIGTTOU) => { "Stopped (tty output)" };
+    (SIGURG) => { "Urgent I/O condition" };
+    (SIGXCPU) => { "CPU time limit exceeded" };
+    (SIGXFSZ) => { "File size limit exceeded" };
+    (SIGVTALRM) => { "Virtual timer expired" };
+    (SIGPROF) => { "Profiling timer expired" };
+    (SIGWINCH) => { "Window changed" };
+    (SIGIO) => { "I/O possible" };
+    (SIGPWR) => { "Power failure" };
+    (SIGSYS) => { "Bad system call" };
+    (SIGINFO) => { "Information request" };
+    (SIGKILLTHR) => { "Kill thread" };
+    (SIGBREAK) => { "Ctrl-Break" };
+}
+
+macro_rules! define_signal {
+    ($($(#[$attr:meta])* $name:ident = $code:literal,)+) => {
+        $(
+            $(#[$attr])*
+            #[doc = description!($name)]
+            pub const $name: c_int = $code;
+        )+
+
+        pub(super) const TABLE: &[(c_int, &str, &str)] = &[
+            $(
+                $(#[$attr])*
+                ($code, stringify!($name), description!($name)),
+            )+
+        ];
+    };
+}
+
+#[cfg(not(any(windows, target_os = "haiku", target_os = "vxworks", target_os = "aix", target_os = "nto", target_os = "solaris", target_os = "illumos", target_vendor = "apple", target_os = "freebsd", target_os = "dragonfly", target_os = "openbsd", target_os = "netbsd", all(target_os = "linux", any(target_arch = "mips", target_arch = "mips32r6", target_arch = "mips64", target_arch = "mips64r6", target_arch = "sparc", target_arch = "sparc64")))))]
+mod sys {
+    use crate::types::c_int;
+
+    define_signal! {
+        SIGHUP = 1,
+        SIGINT = 2,
+        SIGQUIT = 3,
+        SIGILL = 4,
+        SIGTRAP = 5,
+        SIGABRT = 6,
+        SIGBUS = 7,
+        SIGFPE = 8,
+        SIGKILL = 9,
+        SIGUSR1 = 10,
+        SIGSEGV = 11,
+        SIGUSR2 = 12,
+        SIGPIPE = 13,
+        SIGALRM = 14,
+        SIGTERM = 15,
+        SIGSTKFLT = 16,
+        SIGCHLD = 17,
+        SIGCONT = 18,
+        SIGSTOP = 19,
+        SIGTSTP = 20,
+        SIGTTIN = 21,
+        SIGTTOU = 22,
+        SIGURG = 23,
+        SIGXCPU = 24,
+        SIGXFSZ = 25,
+        SIGVTALRM = 26,
+        SIGPROF = 27,
+        SIGWINCH = 28,
+        SIGIO = 29,
+        SIGPWR = 30,
+        SIGSYS = 31,
+    }
+}
+
+#[cfg(any(target_os = "nto", target_os = "solaris", target_os = "illumos", all(target_os = "linux", any(target_arch = "mips", target_arch = "mips32r6", target_arch = "mips64", target_arch = "mips64r6"))))]
+mod sys {
+    use crate::types::c_int;
+
+    define_signal! {
+        SIGHUP = 1,
+        SIGINT = 2,
+        SIGQUIT = 3,
+        SIGILL = 4,
+        SIGTRAP = 5,
+        SIGABRT = 6,
+        SIGEMT = 7,
+        SIGFPE = 8,
+        SIGKILL = 9,
+        SIGBUS = 10,
+        SIGSEGV = 11,
+        SIGSYS = 12,
+        SIGPIPE = 13,
+        SIGALRM = 14,
+        SIGTERM = 15,
+        SIGUSR1 = 16,
+        SIGUSR2 = 17,
+        SIGCHLD = 18,
+        SIGPWR = 19,
+        SIGWINCH = 20,
+        SIGURG = 21,
+        SIGIO = 22,
+        SIGSTOP = 23,
+        SIGTSTP = 24,
+        SIGCONT = 25,
+        SIGTTIN = 26,
+        SIGTTOU = 27,
+        SIGVTALRM = 28,
+        SIGPROF = 29,
+        SIGXCPU = 30,
+        SIGXFSZ = 31,
+    }
+}
+
+#[cfg(any(target_os = "aix", target_vendor = "apple", target_os = "freebsd", target_os = "dragonfly", target_os = "openbsd", target_os = "netbsd", all(target_os = "linux", any(target_arch = "sparc", target_arch = "sparc64"))))]
+mod sys {
+    use crate::types::c_int;
+
+    define_signal! {
+        SIGHUP = 1,
+        SIGINT = 2,
+        SIGQUIT = 3,
+        SIGILL = 4,
+        SIGTRAP = 5,
+        SIGABRT = 6,
+        SIGEMT = 7,
+        SIGFPE = 8,
+        SIGKILL = 9,
+        SIGBUS = 10,
+        SIGSEGV = 11,
+        SIGSYS = 12,
+        SIGPIPE = 13,
+        SIGALRM = 14,
+        SIGTERM = 15,
+        SIGURG = 16,
+        SIGSTOP = 17,
+        SIGTSTP = 18,
+        SIGCONT = 19,
+        SIGCHLD = 20,
+        SIGTTIN = 21,
+        SIGTTOU = 22,
+        SIGIO = 23,
+        SIGXCPU = 24,
+        SIGXFSZ = 25,
+        #[cfg(not(target_os = "aix"))]
+        SIGVTALRM = 26,
+        #[cfg(not(target_os = "aix"))]
+        SIGPROF = 27,
+        SIGWINCH = 28,
+        #[cfg(not(any(target_os = "aix", target_os = "linux")))]
+        SIGINFO = 29,
+        #[cfg(any(target_os = "aix", target_os = "linux"))]
+        SIGPWR = 29,
+        SIGUSR1 = 30,
+        SIGUSR2 = 31,
+        #[cfg(target_os = "aix")]
+        SIGPROF = 32,
+        #[cfg(target_os = "aix")]
+        SIGVTALRM = 34,
+    }
+}
+
+#[cfg(target_os = "haiku")]
+mod sys {
+    use crate::types::c_int;
+
+    define_signal! {
+        SIGHUP = 1,
+        SIGINT = 2,
+        SIGQUIT = 3,
+        SIGILL = 4,
+        SIGCHLD = 5,
+        SIGABRT = 6,
+        SIGPIPE = 7,
+        SIGFPE = 8,
+        SIGKILL = 9,
+        SIGSTOP = 10,
+        SIGSEGV = 11,
+        SIGCONT = 12,
+        SIGTSTP = 13,
+        SIGALRM = 14,
+        SIGTERM = 15,
+        SIGTTIN = 16,
+        SIGTTOU = 17,
+        SIGUSR1 = 18,
+        SIGUSR2 = 19,
+        SIGWINCH = 20,
+        SIGKILLTHR = 21,
+        SIGTRAP = 22,
+        SIGIO = 23,
+        SIGPROF = 24,
+        SIGSYS = 25,
+        SIGURG = 26,
+        SIGVTALRM = 27,
+        SIGXCPU = 28,
+        SIGXFSZ = 29,
+        SIGBUS = 30,
+    }
+}
+
+#[cfg(target_os = "vxworks")]
+mod sys {
+    use crate::types::c_int;
+
+    define_signal! {
+        SIGHUP = 1,
+        SIGINT = 2,
+        SIGQUIT = 3,
+        SIGILL = 4,
+        SIGTRAP = 5,
+        SIGABRT = 6,
+        SIGEMT = 7,
+        SIGFPE = 8,
+        SIGKILL = 9,
+        SIGBUS = 10,
+        SIGSEGV = 11,
+        SIGPIPE = 13,
+        SIGALRM = 14,
+        SIGTERM = 15,
+        SIGSTOP = 17,
+        SIGTSTP = 18,
+        SIGCONT = 19,
+        SIGCHLD = 20,
+        SIGTTIN = 21,
+        SIGTTOU = 22,
+        SIGUSR1 = 30,
+        SIGUSR2 = 31,
+        SIGIO = 32,
+        SIGPROF = 33,
+        SIGSYS = 34,
+        SIGURG = 35,
+        SIGVTALRM = 36,
+        SIGXCPU = 37,
+        SIGXFSZ = 38,
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use crate::types::c_int;
+
+    define_signal! {
+        SIGINT = 2,
+        SIGILL = 4,
+        SIGFPE = 8,
+        SIGSEGV = 11,
+        SIGTERM = 15,
+        SIGBREAK = 21,
+        SIGABRT = 22,
+    }
+}
+
+pub use sys::*;
+
+#[cfg(not(windows))]
+///Alias of `SIGIO`.
+pub const SIGPOLL: c_int = SIGIO;
+///Alias of `SIGABRT`.
+pub const SIGIOT: c_int = SIGABRT;
+
+#[inline]
+fn lookup(code: c_int) -> Option<&'static (c_int, &'static str, &'static str)> {
+    TABLE.iter().find(|entry| entry.0 == code)
+}
+
+#[inline]
+///Returns symbolic name of signal, if it is known.
+///
+///Real-time signals have no fixed names, hence `None` is returned for them.
+pub fn name(code: c_int) -> Option<&'static str> {
+    lookup(code).map(|entry| entry.1)
+}
+
+#[inline]
+///Returns English description of signal, if it is known.
+pub fn description(code: c_int) -> Option<&'static str> {
+    lookup(code).map(|entry| entry.2)
+}
+
+#[cfg(unix)]
+///Retrieves description of the signal from C library and passes it to `cb`.
+fn with_strsignal<R>(code: c_int, cb: impl FnOnce(&[u8]) -> R) -> Option<R> {
+    extern "C" {
+        fn strsignal(code: c_int) -> *const i8;
+        fn strlen(text: *const i8) -> usize;
+    }
+
+    //Outside of Linux, unknown signal may be described using static buffer, which is not thread safe.
+    if cfg!(not(any(target_os = "linux", target_os = "android"))) && lookup(code).is_none() {
+        return None;
+    }
+
+    let err = unsafe {
+        strsignal(code)
+    };
+
+    if err.is_null() {
+        None
+    } else {
+        let err = unsafe {
+            core::slice::from_raw_parts(err as *const u8, strlen(err))
+        };
+        Some(cb(err))
+    }
+}
+
+fn equivalent(code: c_int, other: &ErrorCode) -> bool {
+    ptr::eq(&SIGNAL_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+fn is_would_block(_: c_int) -> bool {
+    false
+}
+
+fn message(code: c_int, out: &mut MessageBuf) -> &str {
+    let mut writer = MessageWriter::new(out);
+    let _ = write_message(code, &mut writer);
+    writer.finish()
+}
+
+fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    #[cfg(unix)]
+    {
+        if let Some(result) = with_strsignal(code, |err| crate::utils::write_utf8_lossy(out, err)) {
+            return result;
+        }
+    }
+
+    out.write_str(description(code).unwrap_or_else(|| generic_map_error_code(code)))
+}
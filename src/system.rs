@@ -1,6 +1,6 @@
 use crate::{Category, ErrorCode};
 #[cfg(not(windows))]
-use crate::posix::{message, is_would_block};
+use crate::posix::{message, is_would_block, write_message};
 #[cfg(not(windows))]
 pub(crate) use crate::posix::get_last_error;
 use crate::types::c_int;
@@ -17,10 +17,16 @@ pub static SYSTEM_CATEGORY: Category = Category {
     message,
     equivalent,
     is_would_block,
+    #[cfg(not(windows))]
+    write_message: Some(write_message),
+    #[cfg(windows)]
+    write_message: None,
 };
 
 fn equivalent(code: c_int, other: &ErrorCode) -> bool {
-    ptr::eq(&SYSTEM_CATEGORY, other.category()) && code == other.raw_code()
+    //On Windows system error codes are Win32 error codes, which are also reported by resolver
+    let other_category = other.category();
+    (ptr::eq(&SYSTEM_CATEGORY, other_category) || (cfg!(windows) && (ptr::eq(&crate::WIN32_CATEGORY, other_category) || ptr::eq(&crate::NETDB_CATEGORY, other_category)))) && code == other.raw_code()
 }
 
 #[cfg(windows)]
@@ -93,7 +99,7 @@ fn message(code: c_int, out: &mut crate::MessageBuf) -> &str {
 #[cfg(windows)]
 #[inline]
 fn is_would_block(code: c_int) -> bool {
-    code == 10035 || crate::posix::is_would_block(code)
+    code == crate::win32::WSAEWOULDBLOCK || crate::posix::is_would_block(code)
 }
 
 #[cfg(windows)]
@@ -1,47 +1,259 @@
 //!Error code utilities
 use crate::types::c_int;
-use crate::MessageBuf;
+use crate::{MessageBuf, ErrorCode, TRUNCATED_MARKER};
 
 use core::{fmt, ptr, slice, cmp};
 
-pub(crate) struct FmtCursor<'a> {
+///Safe writer of error message into [MessageBuf](../type.MessageBuf.html).
+///
+///Once buffer is full, text is truncated at char boundary and ends with [TRUNCATED_MARKER](../constant.TRUNCATED_MARKER.html).
+///
+///## Usage
+///
+///```rust
+///use error_code::utils::MessageWriter;
+///
+///use core::fmt::Write;
+///
+///let mut buf = [core::mem::MaybeUninit::uninit(); error_code::MESSAGE_BUF_SIZE];
+///let mut writer = MessageWriter::new(&mut buf);
+///let _ = write!(writer, "Device {} timed out", 3);
+///assert_eq!(writer.finish(), "Device 3 timed out");
+///```
+pub struct MessageWriter<'a> {
     buf: &'a mut MessageBuf,
-    cursor: usize
+    cursor: usize,
+    truncated: bool,
 }
 
-impl<'a> FmtCursor<'a> {
+impl<'a> MessageWriter<'a> {
     #[inline(always)]
-    fn as_str(&self) -> &'a str {
+    ///Creates new writer, starting at the beginning of the `buf`.
+    pub fn new(buf: &'a mut MessageBuf) -> Self {
+        Self::with_state(buf, 0, false)
+    }
+
+    #[inline(always)]
+    pub(crate) fn with_state(buf: &'a mut MessageBuf, cursor: usize, truncated: bool) -> Self {
+        Self {
+            buf,
+            cursor,
+            truncated,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of written bytes.
+    pub fn len(&self) -> usize {
+        self.cursor
+    }
+
+    #[inline(always)]
+    ///Returns `true` if nothing is written yet.
+    pub fn is_empty(&self) -> bool {
+        self.cursor == 0
+    }
+
+    #[inline(always)]
+    ///Returns `true` if written text had to be truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    #[inline(always)]
+    ///Returns written text.
+    pub fn as_str(&self) -> &str {
         unsafe {
             core::str::from_utf8_unchecked(
                 slice::from_raw_parts(self.buf.as_ptr() as *const u8, self.cursor)
             )
         }
     }
-}
 
-impl<'a> fmt::Write for FmtCursor<'a> {
     #[inline(always)]
-    fn write_str(&mut self, text: &str) -> fmt::Result {
-        let remaining = self.buf.len().saturating_sub(self.cursor);
-        debug_assert!(remaining <= self.buf.len());
-        let size = cmp::min(remaining, text.len());
+    ///Finishes writing, returning written text.
+    pub fn finish(self) -> &'a str {
         unsafe {
-            ptr::copy_nonoverlapping(text.as_ptr(), self.buf.as_mut_ptr().add(self.cursor) as *mut u8, size);
+            core::str::from_utf8_unchecked(
+                slice::from_raw_parts(self.buf.as_ptr() as *const u8, self.cursor)
+            )
         }
-        self.cursor = self.cursor.saturating_add(size);
-        Ok(())
+    }
+
+    #[inline(always)]
+    fn is_char_boundary(&self, idx: usize) -> bool {
+        //Same as `str::is_char_boundary`, but buffer is only initialized up to cursor
+        idx == self.cursor || unsafe {
+            (self.buf[idx].assume_init() as i8) >= -0x40
+        }
+    }
+
+    #[inline(always)]
+    fn copy(&mut self, text: &str) {
+        unsafe {
+            ptr::copy_nonoverlapping(text.as_ptr(), self.buf.as_mut_ptr().add(self.cursor) as *mut u8, text.len());
+        }
+        self.cursor += text.len();
     }
 }
 
-#[cfg(windows)]
-pub(crate) fn write_message_buf<'a>(out: &'a mut MessageBuf, text: &str) -> &'a str {
-    let mut formatter = FmtCursor {
-        buf: out,
-        cursor: 0,
-    };
-    let _ = fmt::Write::write_str(&mut formatter, text);
-    formatter.as_str()
+impl<'a> fmt::Write for MessageWriter<'a> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        if self.truncated {
+            return Err(fmt::Error);
+        }
+
+        let remaining = self.buf.len() - self.cursor;
+        if text.len() <= remaining {
+            self.copy(text);
+            return Ok(());
+        }
+
+        //Text doesn't fit so truncate it at char boundary, leaving space for the marker.
+        let mut size = cmp::min(remaining.saturating_sub(TRUNCATED_MARKER.len()), text.len());
+        while !text.is_char_boundary(size) {
+            size -= 1;
+        }
+        self.copy(&text[..size]);
+
+        //Not enough space left for the marker, so discard previously written characters.
+        let mut cursor = cmp::min(self.cursor, self.buf.len() - TRUNCATED_MARKER.len());
+        while !self.is_char_boundary(cursor) {
+            cursor -= 1;
+        }
+        self.cursor = cursor;
+
+        self.copy(TRUNCATED_MARKER);
+        self.truncated = true;
+        Err(fmt::Error)
+    }
+}
+
+///Writes `text` into `out`, returning written part of it.
+///
+///If `text` doesn't fit `out`, it is truncated at char boundary and ends with [TRUNCATED_MARKER](../constant.TRUNCATED_MARKER.html).
+pub fn write_message_buf<'a>(out: &'a mut MessageBuf, text: &str) -> &'a str {
+    let mut writer = MessageWriter::new(out);
+    let _ = fmt::Write::write_str(&mut writer, text);
+    writer.finish()
+}
+
+///Writes `bytes` into `out` as UTF-8, replacing invalid sequences with `U+FFFD REPLACEMENT CHARACTER`.
+pub fn write_utf8_lossy(out: &mut dyn fmt::Write, mut bytes: &[u8]) -> fmt::Result {
+    loop {
+        match core::str::from_utf8(bytes) {
+            Ok(text) => break out.write_str(text),
+            Err(error) => {
+                let (valid, invalid) = bytes.split_at(error.valid_up_to());
+                out.write_str(unsafe {
+                    core::str::from_utf8_unchecked(valid)
+                })?;
+                out.write_char(char::REPLACEMENT_CHARACTER)?;
+
+                bytes = match error.error_len() {
+                    Some(len) => &invalid[len..],
+                    None => break Ok(()),
+                };
+            }
+        }
+    }
+}
+
+#[inline]
+const fn str_eq(left: &str, right: &str) -> bool {
+    let left = left.as_bytes();
+    let right = right.as_bytes();
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut idx = 0;
+    while idx < left.len() {
+        if left[idx] != right[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+///Returns `true` if `table` contains the same code more than once.
+pub const fn has_duplicate_codes(table: &[(c_int, &str)]) -> bool {
+    let mut idx = 0;
+    while idx < table.len() {
+        let mut next = idx + 1;
+        while next < table.len() {
+            if table[idx].0 == table[next].0 {
+                return true;
+            }
+            next += 1;
+        }
+        idx += 1;
+    }
+    false
+}
+
+///Returns `true` if `table` contains the same message more than once.
+pub const fn has_duplicate_messages(table: &[(c_int, &str)]) -> bool {
+    let mut idx = 0;
+    while idx < table.len() {
+        let mut next = idx + 1;
+        while next < table.len() {
+            if str_eq(table[idx].1, table[next].1) {
+                return true;
+            }
+            next += 1;
+        }
+        idx += 1;
+    }
+    false
+}
+
+///Returns `true` if `table` contains message, which is empty or consists only of whitespace.
+pub const fn has_blank_messages(table: &[(c_int, &str)]) -> bool {
+    let mut idx = 0;
+    'table: while idx < table.len() {
+        let message = table[idx].1.as_bytes();
+        idx += 1;
+
+        let mut byte_idx = 0;
+        while byte_idx < message.len() {
+            if !message[byte_idx].is_ascii_whitespace() {
+                continue 'table;
+            }
+            byte_idx += 1;
+        }
+        return true;
+    }
+    false
+}
+
+///Returns `true` if `ranges` of codes contain empty range or ranges overlapping each other.
+pub const fn has_invalid_ranges(ranges: &[(c_int, c_int, &str)]) -> bool {
+    let mut idx = 0;
+    while idx < ranges.len() {
+        let (start, end, _) = ranges[idx];
+        if start > end {
+            return true;
+        }
+
+        let mut next = idx + 1;
+        while next < ranges.len() {
+            let (next_start, next_end, _) = ranges[next];
+            if start <= next_end && next_start <= end {
+                return true;
+            }
+            next += 1;
+        }
+        idx += 1;
+    }
+    false
+}
+
+#[inline]
+///Looks up message of the `code` within `table`
+pub fn lookup_table(table: &'static [(c_int, &'static str)], code: c_int) -> Option<&'static str> {
+    table.iter().find(|(table_code, _)| *table_code == code).map(|(_, msg)| *msg)
 }
 
 #[inline(always)]
@@ -53,12 +265,34 @@ pub fn generic_map_error_code(code: c_int) -> &'static str {
     }
 }
 
+#[cfg(windows)]
 pub(crate) fn write_fallback_code(out: &mut MessageBuf, code: c_int) -> &str {
-    let mut formatter = FmtCursor {
-        buf: out,
-        cursor: 0,
-    };
+    write_message_buf(out, generic_map_error_code(code))
+}
 
-    let _ = fmt::Write::write_str(&mut formatter, generic_map_error_code(code));
-    formatter.as_str()
+///Formats error code's message as `str` would be with `fmt::Debug`
+pub(crate) struct DebugMessage<'a>(pub &'a ErrorCode);
+
+impl fmt::Debug for DebugMessage<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Escape<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+        impl fmt::Write for Escape<'_, '_> {
+            fn write_str(&mut self, text: &str) -> fmt::Result {
+                for ch in text.chars() {
+                    match ch {
+                        '\'' => self.0.write_char(ch)?,
+                        ch => for escaped in ch.escape_debug() {
+                            self.0.write_char(escaped)?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        fmt::Write::write_char(fmt, '"')?;
+        self.0.write_message(&mut Escape(fmt))?;
+        fmt::Write::write_char(fmt, '"')
+    }
 }
@@ -0,0 +1,348 @@
+//!Win32 error category
+//!
+//!Describes Windows error codes using built-in table, hence it is available on every platform.
+use crate::{Category, ErrorCode, MessageBuf, SYSTEM_CATEGORY};
+use crate::posix::Errno;
+use crate::utils::{write_message_buf, generic_map_error_code};
+use crate::types::c_int;
+
+use core::{fmt, ptr};
+
+///Win32 error category, describing Windows error codes on any platform.
+///
+///Unlike [SYSTEM_CATEGORY](../static.SYSTEM_CATEGORY.html), messages are taken from built-in
+///table of English descriptions instead of `FormatMessageW`.
+///
+///On Windows, error codes of this category are equal to error codes of `SYSTEM_CATEGORY`.
+///
+///## Usage
+///
+///```rust
+///use error_code::ErrorCode;
+///use error_code::win32::{self, WIN32_CATEGORY};
+///use error_code::posix::Errno;
+///
+///let error = ErrorCode::new(win32::WSAEWOULDBLOCK, &WIN32_CATEGORY);
+///assert!(error.is_would_block());
+///assert_eq!(error.to_string(), "Win32Error(10035): A non-blocking socket operation could not be completed immediately.");
+///assert_eq!(win32::name(error.raw_code()), Some("WSAEWOULDBLOCK"));
+///assert_eq!(win32::to_errno(win32::ERROR_FILE_NOT_FOUND), Errno::ENOENT);
+///```
+pub static WIN32_CATEGORY: Category = Category {
+    name: "Win32Error",
+    message,
+    equivalent,
+    is_would_block,
+    write_message: Some(write_message),
+};
+
+macro_rules! define_win32 {
+    ($($name:ident = $code:literal => $message:literal,)+) => {
+        $(
+            #[doc = $message]
+            pub const $name: c_int = $code;
+        )+
+
+        const TABLE: &[(c_int, &str, &str)] = &[
+            $(
+                ($code, stringify!($name), $message),
+            )+
+        ];
+    };
+}
+
+define_win32! {
+    ERROR_SUCCESS = 0 => "The operation completed successfully.",
+    ERROR_INVALID_FUNCTION = 1 => "Incorrect function.",
+    ERROR_FILE_NOT_FOUND = 2 => "The system cannot find the file specified.",
+    ERROR_PATH_NOT_FOUND = 3 => "The system cannot find the path specified.",
+    ERROR_TOO_MANY_OPEN_FILES = 4 => "The system cannot open the file.",
+    ERROR_ACCESS_DENIED = 5 => "Access is denied.",
+    ERROR_INVALID_HANDLE = 6 => "The handle is invalid.",
+    ERROR_ARENA_TRASHED = 7 => "The storage control blocks were destroyed.",
+    ERROR_NOT_ENOUGH_MEMORY = 8 => "Not enough memory resources are available to process this command.",
+    ERROR_INVALID_BLOCK = 9 => "The storage control block address is invalid.",
+    ERROR_BAD_ENVIRONMENT = 10 => "The environment is incorrect.",
+    ERROR_BAD_FORMAT = 11 => "An attempt was made to load a program with an incorrect format.",
+    ERROR_INVALID_ACCESS = 12 => "The access code is invalid.",
+    ERROR_INVALID_DATA = 13 => "The data is invalid.",
+    ERROR_OUTOFMEMORY = 14 => "Not enough memory resources are available to complete this operation.",
+    ERROR_INVALID_DRIVE = 15 => "The system cannot find the drive specified.",
+    ERROR_CURRENT_DIRECTORY = 16 => "The directory cannot be removed.",
+    ERROR_NOT_SAME_DEVICE = 17 => "The system cannot move the file to a different disk drive.",
+    ERROR_NO_MORE_FILES = 18 => "There are no more files.",
+    ERROR_WRITE_PROTECT = 19 => "The media is write protected.",
+    ERROR_BAD_UNIT = 20 => "The system cannot find the device specified.",
+    ERROR_NOT_READY = 21 => "The device is not ready.",
+    ERROR_BAD_COMMAND = 22 => "The device does not recognize the command.",
+    ERROR_CRC = 23 => "Data error (cyclic redundancy check).",
+    ERROR_BAD_LENGTH = 24 => "The program issued a command but the command length is incorrect.",
+    ERROR_SEEK = 25 => "The drive cannot locate a specific area or track on the disk.",
+    ERROR_NOT_DOS_DISK = 26 => "The specified disk or diskette cannot be accessed.",
+    ERROR_SECTOR_NOT_FOUND = 27 => "The drive cannot find the sector requested.",
+    ERROR_OUT_OF_PAPER = 28 => "The printer is out of paper.",
+    ERROR_WRITE_FAULT = 29 => "The system cannot write to the specified device.",
+    ERROR_READ_FAULT = 30 => "The system cannot read from the specified device.",
+    ERROR_GEN_FAILURE = 31 => "A device attached to the system is not functioning.",
+    ERROR_SHARING_VIOLATION = 32 => "The process cannot access the file because it is being used by another process.",
+    ERROR_LOCK_VIOLATION = 33 => "The process cannot access the file because another process has locked a portion of the file.",
+    ERROR_WRONG_DISK = 34 => "The wrong diskette is in the drive.",
+    ERROR_SHARING_BUFFER_EXCEEDED = 36 => "Too many files opened for sharing.",
+    ERROR_HANDLE_EOF = 38 => "Reached the end of the file.",
+    ERROR_HANDLE_DISK_FULL = 39 => "The disk is full.",
+    ERROR_NOT_SUPPORTED = 50 => "The request is not supported.",
+    ERROR_REM_NOT_LIST = 51 => "Windows cannot find the network path.",
+    ERROR_DUP_NAME = 52 => "You were not connected because a duplicate name exists on the network.",
+    ERROR_BAD_NETPATH = 53 => "The network path was not found.",
+    ERROR_NETWORK_BUSY = 54 => "The network is busy.",
+    ERROR_DEV_NOT_EXIST = 55 => "The specified network resource or device is no longer available.",
+    ERROR_UNEXP_NET_ERR = 59 => "An unexpected network error occurred.",
+    ERROR_NETNAME_DELETED = 64 => "The specified network name is no longer available.",
+    ERROR_NETWORK_ACCESS_DENIED = 65 => "Network access is denied.",
+    ERROR_BAD_NET_NAME = 67 => "The network name cannot be found.",
+    ERROR_FILE_EXISTS = 80 => "The file exists.",
+    ERROR_CANNOT_MAKE = 82 => "The directory or file cannot be created.",
+    ERROR_FAIL_I24 = 83 => "Fail on INT 24.",
+    ERROR_INVALID_PARAMETER = 87 => "The parameter is incorrect.",
+    ERROR_NO_PROC_SLOTS = 89 => "The system cannot start another process at this time.",
+    ERROR_DRIVE_LOCKED = 108 => "The disk is in use or locked by another process.",
+    ERROR_BROKEN_PIPE = 109 => "The pipe has been ended.",
+    ERROR_OPEN_FAILED = 110 => "The system cannot open the device or file specified.",
+    ERROR_BUFFER_OVERFLOW = 111 => "The file name is too long.",
+    ERROR_DISK_FULL = 112 => "There is not enough space on the disk.",
+    ERROR_INVALID_TARGET_HANDLE = 114 => "The target internal file identifier is incorrect.",
+    ERROR_CALL_NOT_IMPLEMENTED = 120 => "This function is not supported on this system.",
+    ERROR_SEM_TIMEOUT = 121 => "The semaphore timeout period has expired.",
+    ERROR_INSUFFICIENT_BUFFER = 122 => "The data area passed to a system call is too small.",
+    ERROR_INVALID_NAME = 123 => "The filename, directory name, or volume label syntax is incorrect.",
+    ERROR_MOD_NOT_FOUND = 126 => "The specified module could not be found.",
+    ERROR_PROC_NOT_FOUND = 127 => "The specified procedure could not be found.",
+    ERROR_WAIT_NO_CHILDREN = 128 => "There are no child processes to wait for.",
+    ERROR_CHILD_NOT_COMPLETE = 129 => "The application cannot be run in Win32 mode.",
+    ERROR_DIRECT_ACCESS_HANDLE = 130 => "Attempt to use a file handle to an open disk partition for an operation other than raw disk I/O.",
+    ERROR_NEGATIVE_SEEK = 131 => "An attempt was made to move the file pointer before the beginning of the file.",
+    ERROR_SEEK_ON_DEVICE = 132 => "The file pointer cannot be set on the specified device or file.",
+    ERROR_DIR_NOT_EMPTY = 145 => "The directory is not empty.",
+    ERROR_NOT_LOCKED = 158 => "The segment is already unlocked.",
+    ERROR_BAD_PATHNAME = 161 => "The specified path is invalid.",
+    ERROR_MAX_THRDS_REACHED = 164 => "No more threads can be created in the system.",
+    ERROR_LOCK_FAILED = 167 => "Unable to lock a region of a file.",
+    ERROR_BUSY = 170 => "The requested resource is in use.",
+    ERROR_ALREADY_EXISTS = 183 => "Cannot create a file when that file already exists.",
+    ERROR_SEM_NOT_FOUND = 187 => "The specified system semaphore name was not found.",
+    ERROR_INVALID_STARTING_CODESEG = 188 => "The operating system cannot run this application program.",
+    ERROR_BAD_EXE_FORMAT = 193 => "The application is not a valid Win32 application.",
+    ERROR_INFLOOP_IN_RELOC_CHAIN = 202 => "The operating system cannot run this application program.",
+    ERROR_ENVVAR_NOT_FOUND = 203 => "The system could not find the environment option that was entered.",
+    ERROR_FILENAME_EXCED_RANGE = 206 => "The filename or extension is too long.",
+    ERROR_NESTING_NOT_ALLOWED = 215 => "Cannot nest calls to LoadModule.",
+    ERROR_BAD_PIPE = 230 => "The pipe state is invalid.",
+    ERROR_PIPE_BUSY = 231 => "All pipe instances are busy.",
+    ERROR_NO_DATA = 232 => "The pipe is being closed.",
+    ERROR_PIPE_NOT_CONNECTED = 233 => "No process is on the other end of the pipe.",
+    ERROR_MORE_DATA = 234 => "More data is available.",
+    WAIT_TIMEOUT = 258 => "The wait operation timed out.",
+    ERROR_NO_MORE_ITEMS = 259 => "No more data is available.",
+    ERROR_DIRECTORY = 267 => "The directory name is invalid.",
+    ERROR_PARTIAL_COPY = 299 => "Only part of a ReadProcessMemory or WriteProcessMemory request was completed.",
+    ERROR_MR_MID_NOT_FOUND = 317 => "The system cannot find message text for message number in the message file.",
+    ERROR_INVALID_ADDRESS = 487 => "Attempt to access invalid address.",
+    ERROR_ARITHMETIC_OVERFLOW = 534 => "Arithmetic result exceeded 32 bits.",
+    ERROR_PIPE_CONNECTED = 535 => "There is a process on other end of the pipe.",
+    ERROR_PIPE_LISTENING = 536 => "Waiting for a process to open the other end of the pipe.",
+    ERROR_OPERATION_ABORTED = 995 => "The I/O operation has been aborted because of either a thread exit or an application request.",
+    ERROR_IO_INCOMPLETE = 996 => "Overlapped I/O event is not in a signaled state.",
+    ERROR_IO_PENDING = 997 => "Overlapped I/O operation is in progress.",
+    ERROR_NOACCESS = 998 => "Invalid access to memory location.",
+    ERROR_SWAPERROR = 999 => "Error performing inpage operation.",
+    ERROR_STACK_OVERFLOW = 1001 => "Recursion too deep; the stack overflowed.",
+    ERROR_INVALID_FLAGS = 1004 => "Invalid flags.",
+    ERROR_DLL_INIT_FAILED = 1114 => "A dynamic link library (DLL) initialization routine failed.",
+    ERROR_IO_DEVICE = 1117 => "The request could not be performed because of an I/O device error.",
+    ERROR_NOT_FOUND = 1168 => "Element not found.",
+    ERROR_CANCELLED = 1223 => "The operation was canceled by the user.",
+    ERROR_CONNECTION_REFUSED = 1225 => "The remote computer refused the network connection.",
+    ERROR_CONNECTION_INVALID = 1229 => "An operation was attempted on a nonexistent network connection.",
+    ERROR_NETWORK_UNREACHABLE = 1231 => "The network location cannot be reached.",
+    ERROR_HOST_UNREACHABLE = 1232 => "The remote host cannot be reached.",
+    ERROR_CONNECTION_ABORTED = 1236 => "The network connection was aborted by the local system.",
+    ERROR_PRIVILEGE_NOT_HELD = 1314 => "A required privilege is not held by the client.",
+    ERROR_LOGON_FAILURE = 1326 => "The user name or password is incorrect.",
+    ERROR_INTERNAL_ERROR = 1359 => "An internal error occurred.",
+    ERROR_NO_SYSTEM_RESOURCES = 1450 => "Insufficient system resources exist to complete the requested service.",
+    ERROR_TIMEOUT = 1460 => "This operation returned because the timeout period expired.",
+    ERROR_NOT_ENOUGH_QUOTA = 1816 => "Not enough quota is available to process this command.",
+    ERROR_NOT_A_REPARSE_POINT = 4390 => "The file or directory is not a reparse point.",
+    WSAEINTR = 10004 => "A blocking operation was interrupted by a call to WSACancelBlockingCall.",
+    WSAEBADF = 10009 => "The file handle supplied is not valid.",
+    WSAEACCES = 10013 => "An attempt was made to access a socket in a way forbidden by its access permissions.",
+    WSAEFAULT = 10014 => "The system detected an invalid pointer address in attempting to use a pointer argument in a call.",
+    WSAEINVAL = 10022 => "An invalid argument was supplied.",
+    WSAEMFILE = 10024 => "Too many open sockets.",
+    WSAEWOULDBLOCK = 10035 => "A non-blocking socket operation could not be completed immediately.",
+    WSAEINPROGRESS = 10036 => "A blocking operation is currently executing.",
+    WSAEALREADY = 10037 => "An operation was attempted on a non-blocking socket that already had an operation in progress.",
+    WSAENOTSOCK = 10038 => "An operation was attempted on something that is not a socket.",
+    WSAEDESTADDRREQ = 10039 => "A required address was omitted from an operation on a socket.",
+    WSAEMSGSIZE = 10040 => "A message sent on a datagram socket was larger than the internal message buffer or some other network limit, or the buffer used to receive a datagram into was smaller than the datagram itself.",
+    WSAEPROTOTYPE = 10041 => "A protocol was specified in the socket function call that does not support the semantics of the socket type requested.",
+    WSAENOPROTOOPT = 10042 => "An unknown, invalid, or unsupported option or level was specified in a getsockopt or setsockopt call.",
+    WSAEPROTONOSUPPORT = 10043 => "The requested protocol has not been configured into the system, or no implementation for it exists.",
+    WSAESOCKTNOSUPPORT = 10044 => "The support for the specified socket type does not exist in this address family.",
+    WSAEOPNOTSUPP = 10045 => "The attempted operation is not supported for the type of object referenced.",
+    WSAEPFNOSUPPORT = 10046 => "The protocol family has not been configured into the system or no implementation for it exists.",
+    WSAEAFNOSUPPORT = 10047 => "An address incompatible with the requested protocol was used.",
+    WSAEADDRINUSE = 10048 => "Only one usage of each socket address (protocol/network address/port) is normally permitted.",
+    WSAEADDRNOTAVAIL = 10049 => "The requested address is not valid in its context.",
+    WSAENETDOWN = 10050 => "A socket operation encountered a dead network.",
+    WSAENETUNREACH = 10051 => "A socket operation was attempted to an unreachable network.",
+    WSAENETRESET = 10052 => "The connection has been broken due to keep-alive activity detecting a failure while the operation was in progress.",
+    WSAECONNABORTED = 10053 => "An established connection was aborted by the software in your host machine.",
+    WSAECONNRESET = 10054 => "An existing connection was forcibly closed by the remote host.",
+    WSAENOBUFS = 10055 => "An operation on a socket could not be performed because the system lacked sufficient buffer space or because a queue was full.",
+    WSAEISCONN = 10056 => "A connect request was made on an already connected socket.",
+    WSAENOTCONN = 10057 => "A request to send or receive data was disallowed because the socket is not connected and (when sending on a datagram socket using a sendto call) no address was supplied.",
+    WSAESHUTDOWN = 10058 => "A request to send or receive data was disallowed because the socket had already been shut down in that direction with a previous shutdown call.",
+    WSAETOOMANYREFS = 10059 => "Too many references to some kernel object.",
+    WSAETIMEDOUT = 10060 => "A connection attempt failed because the connected party did not properly respond after a period of time, or established connection failed because connected host has failed to respond.",
+    WSAECONNREFUSED = 10061 => "No connection could be made because the target machine actively refused it.",
+    WSAELOOP = 10062 => "Cannot translate name.",
+    WSAENAMETOOLONG = 10063 => "Name component or name was too long.",
+    WSAEHOSTDOWN = 10064 => "A socket operation failed because the destination host was down.",
+    WSAEHOSTUNREACH = 10065 => "A socket operation was attempted to an unreachable host.",
+    WSAENOTEMPTY = 10066 => "Cannot remove a directory that is not empty.",
+    WSAEPROCLIM = 10067 => "A Windows Sockets implementation may have a limit on the number of applications that may use it simultaneously.",
+    WSAEUSERS = 10068 => "Ran out of quota.",
+    WSAEDQUOT = 10069 => "Ran out of disk quota.",
+    WSAESTALE = 10070 => "File handle reference is no longer available.",
+    WSAEREMOTE = 10071 => "Item is not available locally.",
+    WSASYSNOTREADY = 10091 => "WSAStartup cannot function at this time because the underlying system it uses to provide network services is currently unavailable.",
+    WSAVERNOTSUPPORTED = 10092 => "The Windows Sockets version requested is not supported.",
+    WSANOTINITIALISED = 10093 => "Either the application has not called WSAStartup, or WSAStartup failed.",
+    WSAEDISCON = 10101 => "Returned by WSARecv or WSARecvFrom to indicate the remote party has initiated a graceful shutdown sequence.",
+    WSAENOMORE = 10102 => "No more results can be returned by WSALookupServiceNext.",
+    WSAECANCELLED = 10103 => "A call to WSALookupServiceEnd was made while this call was still processing. The call has been canceled.",
+    WSATYPE_NOT_FOUND = 10109 => "The specified class was not found.",
+    WSAHOST_NOT_FOUND = 11001 => "No such host is known.",
+    WSATRY_AGAIN = 11002 => "This is usually a temporary error during hostname resolution and means that the local server did not receive a response from an authoritative server.",
+    WSANO_RECOVERY = 11003 => "A non-recoverable error occurred during a database lookup.",
+    WSANO_DATA = 11004 => "The requested name is valid, but no data of the requested type was found.",
+}
+
+const _: () = {
+    let mut idx = 1;
+    while idx < TABLE.len() {
+        assert!(TABLE[idx - 1].0 < TABLE[idx].0, "Win32 table must be sorted by code");
+        idx += 1;
+    }
+};
+
+#[inline]
+fn lookup(code: c_int) -> Option<&'static (c_int, &'static str, &'static str)> {
+    TABLE.binary_search_by_key(&code, |entry| entry.0).ok().map(|idx| &TABLE[idx])
+}
+
+#[inline]
+///Returns symbolic name of Win32 error code, if it is known.
+pub fn name(code: c_int) -> Option<&'static str> {
+    lookup(code).map(|entry| entry.1)
+}
+
+#[inline]
+///Returns English description of Win32 error code, if it is known.
+pub fn description(code: c_int) -> Option<&'static str> {
+    lookup(code).map(|entry| entry.2)
+}
+
+///Maps Win32 error code to POSIX error code, similarly to `_dosmaperr` of Windows C runtime.
+///
+///In addition to codes mapped by `_dosmaperr`, socket errors are mapped to corresponding POSIX
+///error codes.
+///
+///Returns `None` for error codes without corresponding POSIX error code.
+pub const fn try_to_errno(code: c_int) -> Option<Errno> {
+    match code {
+        ERROR_FILE_NOT_FOUND | ERROR_PATH_NOT_FOUND | ERROR_INVALID_DRIVE | ERROR_NO_MORE_FILES |
+        ERROR_BAD_NETPATH | ERROR_BAD_NET_NAME | ERROR_BAD_PATHNAME | ERROR_FILENAME_EXCED_RANGE => Some(Errno::ENOENT),
+        ERROR_TOO_MANY_OPEN_FILES => Some(Errno::EMFILE),
+        ERROR_ACCESS_DENIED | ERROR_CURRENT_DIRECTORY | ERROR_NETWORK_ACCESS_DENIED | ERROR_CANNOT_MAKE |
+        ERROR_FAIL_I24 | ERROR_DRIVE_LOCKED | ERROR_SEEK_ON_DEVICE | ERROR_NOT_LOCKED | ERROR_LOCK_FAILED => Some(Errno::EACCES),
+        //Range from ERROR_WRITE_PROTECT to ERROR_SHARING_BUFFER_EXCEEDED
+        19..=36 => Some(Errno::EACCES),
+        ERROR_INVALID_HANDLE | ERROR_INVALID_TARGET_HANDLE | ERROR_DIRECT_ACCESS_HANDLE => Some(Errno::EBADF),
+        ERROR_ARENA_TRASHED | ERROR_NOT_ENOUGH_MEMORY | ERROR_INVALID_BLOCK | ERROR_NOT_ENOUGH_QUOTA => Some(Errno::ENOMEM),
+        ERROR_BAD_ENVIRONMENT => Some(Errno::E2BIG),
+        ERROR_BAD_FORMAT => Some(Errno::ENOEXEC),
+        //Range from ERROR_INVALID_STARTING_CODESEG to ERROR_INFLOOP_IN_RELOC_CHAIN
+        188..=202 => Some(Errno::ENOEXEC),
+        ERROR_NOT_SAME_DEVICE => Some(Errno::EXDEV),
+        ERROR_FILE_EXISTS | ERROR_ALREADY_EXISTS => Some(Errno::EEXIST),
+        ERROR_NO_PROC_SLOTS | ERROR_MAX_THRDS_REACHED | ERROR_NESTING_NOT_ALLOWED => Some(Errno::EAGAIN),
+        ERROR_BROKEN_PIPE => Some(Errno::EPIPE),
+        ERROR_DISK_FULL => Some(Errno::ENOSPC),
+        ERROR_WAIT_NO_CHILDREN | ERROR_CHILD_NOT_COMPLETE => Some(Errno::ECHILD),
+        ERROR_DIR_NOT_EMPTY => Some(Errno::ENOTEMPTY),
+        ERROR_INVALID_FUNCTION | ERROR_INVALID_ACCESS | ERROR_INVALID_DATA | ERROR_INVALID_PARAMETER | ERROR_NEGATIVE_SEEK => Some(Errno::EINVAL),
+        WSAEINTR => Some(Errno::EINTR),
+        WSAEBADF => Some(Errno::EBADF),
+        WSAEACCES => Some(Errno::EACCES),
+        WSAEFAULT => Some(Errno::EFAULT),
+        WSAEINVAL => Some(Errno::EINVAL),
+        WSAEMFILE => Some(Errno::EMFILE),
+        WSAEWOULDBLOCK => Some(Errno::EWOULDBLOCK),
+        WSAEINPROGRESS => Some(Errno::EINPROGRESS),
+        WSAEALREADY => Some(Errno::EALREADY),
+        WSAENOTSOCK => Some(Errno::ENOTSOCK),
+        WSAEDESTADDRREQ => Some(Errno::EDESTADDRREQ),
+        WSAEMSGSIZE => Some(Errno::EMSGSIZE),
+        WSAEPROTOTYPE => Some(Errno::EPROTOTYPE),
+        WSAENOPROTOOPT => Some(Errno::ENOPROTOOPT),
+        WSAEPROTONOSUPPORT => Some(Errno::EPROTONOSUPPORT),
+        WSAEOPNOTSUPP => Some(Errno::EOPNOTSUPP),
+        WSAEAFNOSUPPORT => Some(Errno::EAFNOSUPPORT),
+        WSAEADDRINUSE => Some(Errno::EADDRINUSE),
+        WSAEADDRNOTAVAIL => Some(Errno::EADDRNOTAVAIL),
+        WSAENETDOWN => Some(Errno::ENETDOWN),
+        WSAENETUNREACH => Some(Errno::ENETUNREACH),
+        WSAENETRESET => Some(Errno::ENETRESET),
+        WSAECONNABORTED => Some(Errno::ECONNABORTED),
+        WSAECONNRESET => Some(Errno::ECONNRESET),
+        WSAENOBUFS => Some(Errno::ENOBUFS),
+        WSAEISCONN => Some(Errno::EISCONN),
+        WSAENOTCONN => Some(Errno::ENOTCONN),
+        WSAETIMEDOUT => Some(Errno::ETIMEDOUT),
+        WSAECONNREFUSED => Some(Errno::ECONNREFUSED),
+        WSAELOOP => Some(Errno::ELOOP),
+        WSAENAMETOOLONG => Some(Errno::ENAMETOOLONG),
+        WSAEHOSTUNREACH => Some(Errno::EHOSTUNREACH),
+        WSAENOTEMPTY => Some(Errno::ENOTEMPTY),
+        _ => None,
+    }
+}
+
+///Maps Win32 error code to POSIX error code, same as [try_to_errno](fn.try_to_errno.html).
+///
+///Unknown error codes are mapped to `EINVAL`, same as `_dosmaperr` does.
+pub const fn to_errno(code: c_int) -> Errno {
+    match try_to_errno(code) {
+        Some(errno) => errno,
+        None => Errno::EINVAL,
+    }
+}
+
+fn equivalent(code: c_int, other: &ErrorCode) -> bool {
+    let other_category = other.category();
+    (ptr::eq(&WIN32_CATEGORY, other_category) || (cfg!(windows) && (ptr::eq(&SYSTEM_CATEGORY, other_category) || ptr::eq(&crate::NETDB_CATEGORY, other_category)))) && code == other.raw_code()
+}
+
+fn is_would_block(code: c_int) -> bool {
+    code == WSAEWOULDBLOCK
+}
+
+fn message(code: c_int, out: &mut MessageBuf) -> &str {
+    write_message_buf(out, description(code).unwrap_or_else(|| generic_map_error_code(code)))
+}
+
+fn write_message(code: c_int, out: &mut dyn fmt::Write) -> fmt::Result {
+    out.write_str(description(code).unwrap_or_else(|| generic_map_error_code(code)))
+}
@@ -69,3 +69,1013 @@ fn check_error_code_range() {
         }
     }
 }
+
+error_code::define_category!(
+    ///Test category
+    pub enum TestError {
+        ///Success
+        Success = 0,
+        ///Something went wrong
+        Wrong = 1,
+    }
+);
+
+#[test]
+fn check_message() {
+    let error = ErrorCode::from(TestError::Wrong);
+    let message = error.message();
+    assert_eq!(message, "Something went wrong");
+    assert_eq!(message.len(), "Something went wrong".len());
+    assert_eq!(message.clone(), message);
+    assert_eq!(format!("{}", message), "Something went wrong");
+    assert_eq!(format!("{:?}", message), "\"Something went wrong\"");
+
+    let error = ErrorCode::new_posix(defs::EAGAIN);
+    let message = error.message();
+    assert!(!message.is_empty());
+    assert_eq!(error.to_string(), format!("PosixError({}): {}", defs::EAGAIN, message));
+
+    let message = error_code::Message::new(&format!("a{}", "ы".repeat(error_code::MESSAGE_BUF_SIZE)));
+    assert_eq!(message.len(), error_code::MESSAGE_BUF_SIZE);
+    assert!(message.chars().skip(1).all(|ch| ch == 'ы' || ch == '…'));
+}
+
+static LONG_CATEGORY: error_code::Category = error_code::Category {
+    name: "LongError",
+    message: long_message,
+    equivalent: long_equivalent,
+    is_would_block: long_is_would_block,
+    write_message: Some(long_write_message),
+};
+
+const LONG_TEXT: &str = "Long error description";
+
+fn long_message(_: error_code::types::c_int, _: &mut error_code::MessageBuf) -> &str {
+    "Truncated"
+}
+
+fn long_write_message(_: error_code::types::c_int, out: &mut dyn core::fmt::Write) -> core::fmt::Result {
+    for _ in 0..32 {
+        out.write_str(LONG_TEXT)?;
+    }
+    Ok(())
+}
+
+fn long_equivalent(code: error_code::types::c_int, other: &ErrorCode) -> bool {
+    core::ptr::eq(&LONG_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+fn long_is_would_block(_: error_code::types::c_int) -> bool {
+    false
+}
+
+#[test]
+fn check_streaming_message() {
+    let error = ErrorCode::new(1, &LONG_CATEGORY);
+    let expected = LONG_TEXT.repeat(32);
+    assert!(expected.len() > error_code::MESSAGE_BUF_SIZE);
+
+    assert_eq!(error.to_string(), format!("LongError(1): {}", expected));
+    assert_eq!(format!("{:?}", error), format!("LongError {{ code: 1, message: {:?} }}", expected));
+    assert_eq!(error.message(), *format!("{}…", &expected[..error_code::MESSAGE_BUF_SIZE - 3]));
+
+    let error = ErrorCode::from(TestError::Wrong);
+    assert_eq!(format!("{:?}", error), "TestError { code: 1, message: \"Something went wrong\" }");
+}
+
+const LONG_UNICODE_TEXT: &str = "Очень длинное описание ошибки, Очень длинное описание ошибки, Очень длинное описание ошибки, Очень длинное описание ошибки, Очень длинное описание ошибки, Очень длинное описание ошибки";
+
+error_code::define_category!(
+    ///Category with long messages
+    pub enum LongUnicodeError {
+        ///Очень длинное описание ошибки, Очень длинное описание ошибки, Очень длинное описание ошибки, Очень длинное описание ошибки, Очень длинное описание ошибки, Очень длинное описание ошибки
+        Long = 1,
+    }
+);
+
+static LONG_UNICODE_CATEGORY: error_code::Category = error_code::Category {
+    name: "LongUnicodeError",
+    message: long_unicode_message,
+    equivalent: long_unicode_equivalent,
+    is_would_block: long_is_would_block,
+    write_message: None,
+};
+
+fn long_unicode_message(_: error_code::types::c_int, out: &mut error_code::MessageBuf) -> &str {
+    error_code::utils::write_message_buf(out, LONG_UNICODE_TEXT)
+}
+
+fn long_unicode_equivalent(code: error_code::types::c_int, other: &ErrorCode) -> bool {
+    core::ptr::eq(&LONG_UNICODE_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+fn assert_truncated(message: &str) {
+    assert!(message.len() <= error_code::MESSAGE_BUF_SIZE);
+    assert!(message.len() > error_code::MESSAGE_BUF_SIZE - 8);
+    assert!(message.ends_with(error_code::TRUNCATED_MARKER));
+    assert!(LONG_UNICODE_TEXT.starts_with(message.trim_end_matches(error_code::TRUNCATED_MARKER)));
+}
+
+#[test]
+fn check_unicode_truncation() {
+    assert!(LONG_UNICODE_TEXT.len() > error_code::MESSAGE_BUF_SIZE);
+
+    let error = ErrorCode::new(1, &LONG_UNICODE_CATEGORY);
+    let message = error.message();
+    assert_truncated(&message);
+    assert_eq!(error.to_string(), format!("LongUnicodeError(1): {}", message));
+
+    let error = ErrorCode::from(LongUnicodeError::Long);
+    assert_eq!(error.to_string(), format!("LongUnicodeError(1): {}", LONG_UNICODE_TEXT));
+    let message = error.message();
+    assert!(message.is_truncated());
+    assert_truncated(&message);
+
+    let mut buf = [core::mem::MaybeUninit::uninit(); error_code::MESSAGE_BUF_SIZE];
+    assert_truncated((error.category().message)(error.raw_code(), &mut buf));
+
+    //Marker must replace already written text when there is no space left for it
+    let mut message = error_code::Message::new(&"ы".repeat(error_code::MESSAGE_BUF_SIZE / 2));
+    assert!(!message.is_truncated());
+    assert!(core::fmt::Write::write_str(&mut message, "ы").is_err());
+    assert!(message.is_truncated());
+    assert_eq!(message.len(), error_code::MESSAGE_BUF_SIZE - 1);
+    assert!(message.ends_with(error_code::TRUNCATED_MARKER));
+    assert!(core::fmt::Write::write_str(&mut message, "a").is_err());
+    assert_eq!(message.len(), error_code::MESSAGE_BUF_SIZE - 1);
+}
+
+#[test]
+fn check_utf8_lossy() {
+    let mut out = String::new();
+    error_code::utils::write_utf8_lossy(&mut out, b"Erreur \xe9trange\xff\xfe!").unwrap();
+    assert_eq!(out, "Erreur \u{FFFD}trange\u{FFFD}\u{FFFD}!");
+
+    let mut out = String::new();
+    error_code::utils::write_utf8_lossy(&mut out, "Ошибка\u{FFFD}".as_bytes()).unwrap();
+    assert_eq!(out, "Ошибка\u{FFFD}");
+
+    let mut out = String::new();
+    error_code::utils::write_utf8_lossy(&mut out, b"Incomplete \xd0").unwrap();
+    assert_eq!(out, "Incomplete \u{FFFD}");
+}
+
+#[cfg(any(windows, unix))]
+#[test]
+fn check_posix_c_locale() {
+    use error_code::posix::{write_message_in, Locale};
+
+    let mut out = String::new();
+    write_message_in(defs::EAGAIN, Locale::C, &mut out).unwrap();
+    assert_eq!(out, "Resource temporarily unavailable");
+
+    let mut out = String::new();
+    write_message_in(defs::EAGAIN, Locale::Current, &mut out).unwrap();
+    assert_eq!(ErrorCode::new_posix(defs::EAGAIN).message(), *out);
+}
+
+#[test]
+fn check_message_writer() {
+    use core::fmt::Write;
+
+    let mut buf = [core::mem::MaybeUninit::uninit(); error_code::MESSAGE_BUF_SIZE];
+    let mut writer = error_code::utils::MessageWriter::new(&mut buf);
+    assert!(writer.is_empty());
+    write!(writer, "Device {} timed out", 3).unwrap();
+    assert_eq!(writer.as_str(), "Device 3 timed out");
+    assert!(!writer.is_truncated());
+
+    for idx in 0.. {
+        if write!(writer, ", устройство {} не отвечает", idx).is_err() {
+            break;
+        }
+    }
+    assert!(writer.is_truncated());
+    assert!(writer.len() <= error_code::MESSAGE_BUF_SIZE);
+    let text = writer.finish();
+    assert!(text.starts_with("Device 3 timed out, устройство 0 не отвечает"));
+    assert!(text.ends_with(error_code::TRUNCATED_MARKER));
+}
+
+const VENDOR_TABLE: &[(error_code::types::c_int, &str)] = &[
+    (0, "Success"),
+    (1, "Device is busy"),
+    (7, "Устройство не подключено"),
+];
+
+error_code::define_table_category!(
+    ///Vendor errors
+    static VENDOR_CATEGORY = ("VendorError", VENDOR_TABLE);
+);
+
+#[test]
+fn check_table_category() {
+    let error = ErrorCode::new(7, &VENDOR_CATEGORY);
+    assert_eq!(error.to_string(), "VendorError(7): Устройство не подключено");
+    assert_eq!(error.message(), "Устройство не подключено");
+    assert_eq!(format!("{:?}", error), "VendorError { code: 7, message: \"Устройство не подключено\" }");
+    assert!(!error.is_would_block());
+
+    let mut buf = [core::mem::MaybeUninit::uninit(); error_code::MESSAGE_BUF_SIZE];
+    assert_eq!((VENDOR_CATEGORY.message)(1, &mut buf), "Device is busy");
+    assert_eq!((VENDOR_CATEGORY.message)(2, &mut buf), error_code::utils::generic_map_error_code(2));
+
+    assert_eq!(error, ErrorCode::new(7, &VENDOR_CATEGORY));
+    assert_ne!(error, ErrorCode::new(1, &VENDOR_CATEGORY));
+    assert_ne!(error, ErrorCode::new_posix(7));
+}
+
+struct RetryError;
+
+impl error_code::ErrorCategory for RetryError {
+    const NAME: &'static str = "RetryError";
+
+    fn category() -> &'static error_code::Category {
+        static CATEGORY: error_code::Category = <RetryError as error_code::ErrorCategory>::DEFINITION;
+        &CATEGORY
+    }
+
+    fn write_message(code: error_code::types::c_int, out: &mut dyn core::fmt::Write) -> core::fmt::Result {
+        match code {
+            0 => out.write_str("Success"),
+            code => write!(out, "Retry in {} seconds", code),
+        }
+    }
+
+    fn is_would_block(code: error_code::types::c_int) -> bool {
+        code > 0
+    }
+
+    fn equivalent(code: error_code::types::c_int, other: &ErrorCode) -> bool {
+        code == 0 && other.raw_code() == 0
+    }
+}
+
+#[test]
+fn check_trait_category() {
+    use error_code::ErrorCategory;
+
+    let error = RetryError::error_code(5);
+    assert_eq!(error.category().name, "RetryError");
+    assert_eq!(error.to_string(), "RetryError(5): Retry in 5 seconds");
+    assert_eq!(error.message(), "Retry in 5 seconds");
+    assert!(error.is_would_block());
+    assert!(!RetryError::error_code(0).is_would_block());
+
+    let mut buf = [core::mem::MaybeUninit::uninit(); error_code::MESSAGE_BUF_SIZE];
+    assert_eq!((RetryError::category().message)(1, &mut buf), "Retry in 1 seconds");
+
+    assert_eq!(error, ErrorCode::new(5, RetryError::category()));
+    assert_ne!(error, RetryError::error_code(4));
+    assert_ne!(error, ErrorCode::new_posix(5));
+    assert_eq!(RetryError::error_code(0), ErrorCode::new_posix(0));
+
+    //Category with the same name is still different category
+    static FAKE_CATEGORY: error_code::Category = error_code::Category {
+        name: "RetryError",
+        message: |_, _| "Fake",
+        equivalent: |code, other| core::ptr::eq(&FAKE_CATEGORY, other.category()) && code == other.raw_code(),
+        is_would_block: |_| false,
+        write_message: None,
+    };
+    let fake = ErrorCode::new(5, &FAKE_CATEGORY);
+    assert_ne!(error, fake);
+    assert_ne!(fake, error);
+}
+
+#[test]
+fn check_table_validation() {
+    use error_code::utils::{has_duplicate_codes, has_duplicate_messages, has_blank_messages};
+
+    assert!(!has_duplicate_codes(VENDOR_TABLE));
+    assert!(!has_duplicate_messages(VENDOR_TABLE));
+    assert!(!has_blank_messages(VENDOR_TABLE));
+
+    assert!(has_duplicate_codes(&[(1, "One"), (2, "Two"), (1, "Uno")]));
+    assert!(has_duplicate_messages(&[(1, "One"), (2, "Two"), (3, "One")]));
+    assert!(!has_duplicate_messages(&[(1, "One"), (2, "Ones")]));
+    assert!(has_blank_messages(&[(1, "One"), (2, "")]));
+    assert!(has_blank_messages(&[(1, " \t")]));
+    assert!(!has_blank_messages(&[(1, " One")]));
+}
+
+error_code::define_category!(
+    ///HTTP status
+    pub enum HttpStatus {
+        ///OK
+        Ok = 200,
+        ///Not Found
+        NotFound = 404,
+    }
+    100..=199 => "Informational",
+    400..=499 => "Client error",
+    500..=599 => "Server error",
+    _ => "Unknown status",
+);
+
+error_code::define_category!(
+    ///Firmware errors
+    pub enum FirmwareError {
+        ///Checksum mismatch
+        Checksum = -1,
+    }
+    -99..=-10 => "Vendor specific error",
+);
+
+#[test]
+fn check_category_ranges() {
+    assert_eq!(HttpStatus::map_code(200), Some("OK"));
+    assert_eq!(HttpStatus::map_code(404), Some("Not Found"));
+    assert_eq!(HttpStatus::map_code(100), Some("Informational"));
+    assert_eq!(HttpStatus::map_code(199), Some("Informational"));
+    assert_eq!(HttpStatus::map_code(403), Some("Client error"));
+    assert_eq!(HttpStatus::map_code(503), Some("Server error"));
+    assert_eq!(HttpStatus::map_code(0), Some("Unknown status"));
+    assert_eq!(HttpStatus::map_code(302), Some("Unknown status"));
+
+    let category = HttpStatus::Ok.into_error_code().category();
+    assert_eq!(ErrorCode::new(503, category).to_string(), "HttpStatus(503): Server error");
+    assert_eq!(ErrorCode::new(302, category).message(), "Unknown status");
+
+    assert_eq!(FirmwareError::map_code(-50), Some("Vendor specific error"));
+    assert_eq!(FirmwareError::map_code(-5), None);
+    let category = FirmwareError::Checksum.into_error_code().category();
+    assert_eq!(ErrorCode::new(-5, category).message(), error_code::utils::generic_map_error_code(-5));
+    assert_eq!(ErrorCode::new(-10, category).to_string(), "FirmwareError(-10): Vendor specific error");
+
+    assert!(!error_code::utils::has_invalid_ranges(&[(1, 1, "One"), (2, 10, "Many")]));
+    assert!(error_code::utils::has_invalid_ranges(&[(2, 1, "Empty")]));
+    assert!(error_code::utils::has_invalid_ranges(&[(1, 5, "One"), (5, 10, "Many")]));
+}
+
+error_code::define_error_set!(
+    ///Would block errors
+    pub enum WouldBlockError: POSIX {
+        EWOULDBLOCK,
+        EAGAIN,
+    }
+);
+
+error_code::define_error_set!(
+    ///Open errors
+    enum OpenError: POSIX { ENOENT, EACCES, EEXIST }
+);
+
+#[cfg(not(windows))]
+error_code::define_error_set!(
+    ///System errors
+    enum SystemError: SYSTEM { EACCES }
+);
+
+#[cfg(windows)]
+error_code::define_error_set!(
+    ///System errors
+    enum SystemError: SYSTEM { ERROR_ACCESS_DENIED }
+);
+
+#[test]
+fn check_error_set() {
+    use core::convert::TryFrom;
+
+    let error = ErrorCode::from(WouldBlockError::EAGAIN);
+    assert_eq!(error, ErrorCode::new_posix(defs::EAGAIN));
+    assert_eq!(WouldBlockError::EWOULDBLOCK.raw_code(), defs::EWOULDBLOCK);
+    assert_eq!(WouldBlockError::EAGAIN.to_string(), error.to_string());
+
+    let error = WouldBlockError::try_from(ErrorCode::new_posix(defs::EAGAIN)).expect("to convert");
+    if defs::EAGAIN == defs::EWOULDBLOCK {
+        assert_eq!(error, WouldBlockError::EWOULDBLOCK);
+    } else {
+        assert_eq!(error, WouldBlockError::EAGAIN);
+    }
+    assert_eq!(WouldBlockError::try_from(ErrorCode::new_posix(defs::EWOULDBLOCK)), Ok(WouldBlockError::EWOULDBLOCK));
+
+    assert_eq!(WouldBlockError::try_from(ErrorCode::new_posix(0)), Err(ErrorCode::new_posix(0)));
+    assert_eq!(WouldBlockError::try_from(ErrorCode::new_system(0)), Err(ErrorCode::new_system(0)));
+
+    assert_eq!(OpenError::try_from(ErrorCode::new_posix(defs::EEXIST)), Ok(OpenError::EEXIST));
+
+    //SYSTEM codes are Win32 error codes on Windows
+    let access_denied = if cfg!(windows) { 5 } else { defs::EACCES };
+    let error = ErrorCode::new_system(access_denied);
+    assert_eq!(SystemError::try_from(error).map(ErrorCode::from), Ok(error));
+    assert!(error_code::matches_code!(error, SYSTEM: 5 | 0) == cfg!(windows));
+}
+
+#[test]
+fn check_matches() {
+    let error = ErrorCode::new_system(defs::EWOULDBLOCK);
+    assert!(error.is_category(&error_code::SYSTEM_CATEGORY));
+    assert!(!error.is_category(&error_code::POSIX_CATEGORY));
+
+    #[cfg(not(windows))]
+    {
+        assert!(error_code::matches_code!(error, SYSTEM: EWOULDBLOCK));
+        assert!(error_code::matches_code!(error, SYSTEM: 0 | EWOULDBLOCK));
+    }
+    #[cfg(windows)]
+    {
+        let error = ErrorCode::new_system(10035);
+        assert!(error_code::matches_code!(error, SYSTEM: WSAEWOULDBLOCK));
+        assert!(error_code::matches_code!(error, SYSTEM: 0 | WSAEWOULDBLOCK));
+    }
+    assert!(!error_code::matches_code!(error, SYSTEM: 0 | 1));
+    assert!(!error_code::matches_code!(error, POSIX: EWOULDBLOCK | EAGAIN));
+
+    let error = ErrorCode::new_posix(0);
+    assert!(error_code::matches_code!(&error, POSIX: 0));
+    assert!(error_code::matches_code!(ErrorCode::new_posix(5), POSIX: 5));
+    assert!(error_code::matches_code!(ErrorCode::new_posix(-1), POSIX: -1));
+    assert!(error_code::matches_code!(ErrorCode::new_posix(-1), POSIX: EAGAIN | -1 | 2));
+    assert!(!error_code::matches_code!(ErrorCode::new_posix(1), POSIX: -1));
+}
+
+#[test]
+fn check_result_ext() {
+    use error_code::{ResultExt, EXIT_CATEGORY, WIN32_CATEGORY};
+    use error_code::posix::Errno;
+
+    let would_block: Result<u32, ErrorCode> = Err(ErrorCode::new_posix(defs::EAGAIN));
+    let failed: Result<u32, ErrorCode> = Err(ErrorCode::new_posix(1));
+
+    assert_eq!(would_block.ignore_code(Errno::EAGAIN), Ok(0));
+    assert_eq!(would_block.ignore_code(Errno::EWOULDBLOCK), Ok(0));
+    assert_eq!(failed.ignore_code(Errno::EAGAIN), failed);
+    assert_eq!(Ok::<u32, ErrorCode>(5).ignore_code(Errno::EPERM), Ok(5));
+
+    let exists = Errno::EEXIST.raw();
+    let win32: Result<u32, ErrorCode> = Err(ErrorCode::new(exists, &WIN32_CATEGORY));
+    assert_eq!(win32.ignore_code(Errno::EEXIST), win32);
+    let exit: Result<u32, ErrorCode> = Err(ErrorCode::new(exists, &EXIT_CATEGORY));
+    assert_eq!(exit.ignore_code(Errno::EEXIST), exit);
+    assert_eq!(exit.or_retry_on(Errno::EEXIST, || unreachable!()), exit);
+
+    assert_eq!(would_block.map_would_block_to_none(), Ok(None));
+    assert_eq!(failed.map_would_block_to_none(), Err(ErrorCode::new_posix(1)));
+    assert_eq!(Ok::<u32, ErrorCode>(5).map_would_block_to_none(), Ok(Some(5)));
+
+    let error = failed.context("Reading config").unwrap_err();
+    assert_eq!(error.context(), "Reading config");
+    assert_eq!(error.code(), ErrorCode::new_posix(1));
+    assert_eq!(error.to_string(), format!("Reading config: {}", ErrorCode::new_posix(1)));
+    assert_eq!(ErrorCode::from(error), ErrorCode::new_posix(1));
+
+    let mut attempts = 0;
+    let result = would_block.or_retry_on(Errno::EAGAIN, || {
+        attempts += 1;
+        match attempts {
+            3 => Ok(3),
+            _ => Err(ErrorCode::new_posix(defs::EAGAIN)),
+        }
+    });
+    assert_eq!(result, Ok(3));
+    assert_eq!(attempts, 3);
+    assert_eq!(failed.or_retry_on(Errno::EAGAIN, || unreachable!()), failed);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_io_result_ext() {
+    use error_code::ResultExt;
+    use error_code::posix::Errno;
+    use std::io;
+
+    #[cfg(not(windows))]
+    const WOULD_BLOCK: i32 = defs::EWOULDBLOCK;
+    #[cfg(windows)]
+    const WOULD_BLOCK: i32 = error_code::win32::WSAEWOULDBLOCK;
+
+    let would_block = || -> io::Result<u32> { Err(io::Error::from_raw_os_error(WOULD_BLOCK)) };
+    assert_eq!(would_block().ignore_code(Errno::EWOULDBLOCK).unwrap(), 0);
+    assert_eq!(would_block().ignore_code(Errno::EAGAIN).unwrap(), 0);
+    assert!(would_block().map_would_block_to_none().unwrap().is_none());
+    assert!(would_block().ignore_code(Errno::EPERM).is_err());
+    assert!(Err::<u32, _>(io::Error::other("Custom")).ignore_code(Errno::EPERM).is_err());
+
+    let error = would_block().context("Reading socket").unwrap_err();
+    assert_eq!(error.code(), ErrorCode::new_posix(WOULD_BLOCK));
+
+    let error = Err::<u32, _>(io::Error::other("Custom")).context("Custom").unwrap_err();
+    assert_eq!(error.code(), ErrorCode::new_posix(-1));
+}
+
+#[test]
+fn check_defs_match_libc() {
+    macro_rules! assert_libc {
+        ($($name:ident),+) => {
+            $(
+                assert_eq!(defs::$name, libc::$name, stringify!($name));
+            )+
+        };
+    }
+
+    assert_libc!(
+        E2BIG, EACCES, EADDRINUSE, EADDRNOTAVAIL, EAFNOSUPPORT, EAGAIN, EALREADY, EBADF, EBADMSG, EBUSY,
+        ECANCELED, ECHILD, ECONNABORTED, ECONNREFUSED, ECONNRESET, EDEADLK, EDESTADDRREQ, EDOM, EEXIST, EFAULT,
+        EFBIG, EHOSTUNREACH, EIDRM, EILSEQ, EINPROGRESS, EINTR, EINVAL, EIO, EISCONN, EISDIR,
+        ELOOP, EMFILE, EMLINK, EMSGSIZE, ENAMETOOLONG, ENETDOWN, ENETRESET, ENETUNREACH, ENFILE, ENOBUFS,
+        ENODEV, ENOENT, ENOEXEC, ENOLCK, ENOMEM, ENOMSG, ENOPROTOOPT, ENOSPC, ENOSYS, ENOTCONN,
+        ENOTDIR, ENOTEMPTY, ENOTSOCK, ENOTSUP, ENOTTY, ENXIO, EOPNOTSUPP, EOVERFLOW, EPERM, EPIPE,
+        EPROTO, EPROTONOSUPPORT, EPROTOTYPE, ERANGE, EROFS, ESPIPE, ESRCH, ETIMEDOUT, ETXTBSY, EWOULDBLOCK,
+        EXDEV
+    );
+
+    #[cfg(any(target_os = "linux", windows))]
+    assert_libc!(EDEADLOCK);
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    assert_libc!(
+        EDQUOT, EMULTIHOP, ENODATA, ENOLINK, ENOSR, ENOSTR, ENOTRECOVERABLE, EOWNERDEAD, ESTALE, ETIME
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_defs_io_error_kind() {
+    use std::io::{Error, ErrorKind};
+
+    assert_eq!(Error::from_raw_os_error(defs::ENOENT).kind(), ErrorKind::NotFound);
+    assert_eq!(Error::from_raw_os_error(defs::EACCES).kind(), ErrorKind::PermissionDenied);
+    assert_eq!(Error::from_raw_os_error(defs::EINTR).kind(), ErrorKind::Interrupted);
+    assert_eq!(Error::from_raw_os_error(defs::ECONNRESET).kind(), ErrorKind::ConnectionReset);
+    assert_eq!(Error::from_raw_os_error(defs::ETIMEDOUT).kind(), ErrorKind::TimedOut);
+    assert_eq!(Error::from_raw_os_error(defs::EWOULDBLOCK).kind(), ErrorKind::WouldBlock);
+}
+
+#[test]
+fn check_defs_other_platforms() {
+    assert_eq!(defs::linux::EWOULDBLOCK, defs::linux::EAGAIN);
+    assert_eq!(defs::linux::ECONNRESET, 104);
+    assert_eq!(defs::linux_mips::ECONNRESET, 131);
+    assert_eq!(defs::linux::EDEADLOCK, defs::linux::EDEADLK);
+    assert_eq!(defs::linux_powerpc::EDEADLOCK, 58);
+    assert_eq!(defs::linux_powerpc::ECONNRESET, defs::linux::ECONNRESET);
+    assert_eq!(defs::apple::EAGAIN, 35);
+    assert_ne!(defs::apple::ENOTSUP, defs::apple::EOPNOTSUPP);
+    assert_eq!(defs::freebsd::ENOTSUP, defs::freebsd::EOPNOTSUPP);
+    assert_eq!(defs::wasi::EAGAIN, 6);
+    assert_eq!(defs::emscripten::EAGAIN, defs::wasi::EAGAIN);
+    assert_eq!(defs::windows::EWOULDBLOCK, 140);
+    assert_eq!(defs::windows::ETIMEDOUT, 138);
+    assert_eq!(defs::redox::EWOULDBLOCK, 41);
+    assert_eq!(defs::haiku::EAGAIN, -2147483637);
+}
+
+#[test]
+fn check_errno() {
+    use error_code::posix::Errno;
+
+    assert_eq!(Errno::ENOENT.raw(), defs::ENOENT);
+    assert_eq!(Errno::from_raw(defs::ECONNRESET), Some(Errno::ECONNRESET));
+    assert_eq!(Errno::from_raw(defs::EAGAIN), Some(Errno::EAGAIN));
+    assert_eq!(Errno::from_raw(defs::EWOULDBLOCK).map(Errno::raw), Some(defs::EWOULDBLOCK));
+    assert_eq!(Errno::from_raw(0), None);
+    assert_eq!(Errno::ETIMEDOUT.name(), "ETIMEDOUT");
+
+    let error = ErrorCode::from(Errno::EINTR);
+    assert_eq!(error, ErrorCode::new_posix(defs::EINTR));
+    assert_eq!(error.as_errno(), Some(Errno::EINTR));
+    assert_eq!(Errno::EINTR.to_string(), error.to_string());
+    #[cfg(not(windows))]
+    assert_eq!(ErrorCode::new_system(defs::EINTR).as_errno(), Some(Errno::EINTR));
+    assert_eq!(ErrorCode::new(defs::EINTR, &VENDOR_CATEGORY).as_errno(), None);
+}
+
+#[test]
+fn check_translate() {
+    use error_code::posix::{translate, normalize_in, Errno, Platform};
+
+    #[cfg(all(any(target_os = "linux", target_os = "android"), not(target_env = "newlib"), not(any(target_arch = "mips", target_arch = "mips32r6", target_arch = "mips64", target_arch = "mips64r6", target_arch = "sparc", target_arch = "sparc64", target_arch = "powerpc", target_arch = "powerpc64"))))]
+    assert_eq!(Platform::CURRENT, Platform::Linux);
+    #[cfg(target_os = "macos")]
+    assert_eq!(Platform::CURRENT, Platform::Apple);
+    #[cfg(windows)]
+    assert_eq!(Platform::CURRENT, Platform::Windows);
+    assert_eq!(translate(defs::apple::ENOTSUP, Platform::Apple, Platform::Linux), Some(defs::linux::ENOTSUP));
+    assert_eq!(translate(defs::apple::EOPNOTSUPP, Platform::Apple, Platform::Linux), Some(defs::linux::EOPNOTSUPP));
+    assert_eq!(translate(35, Platform::Apple, Platform::Linux), Some(defs::linux::EAGAIN));
+    assert_eq!(translate(35, Platform::Linux, Platform::Apple), Some(defs::apple::EDEADLK));
+    assert_eq!(translate(defs::linux::ETIMEDOUT, Platform::Linux, Platform::Windows), Some(defs::windows::ETIMEDOUT));
+    assert_eq!(translate(defs::haiku::EAGAIN, Platform::Haiku, Platform::Wasi), Some(defs::wasi::EAGAIN));
+    assert_eq!(translate(defs::wasi::ENOTSUP, Platform::Wasi, Platform::Emscripten), Some(defs::emscripten::ENOTSUP));
+    assert_eq!(translate(defs::windows::EWOULDBLOCK, Platform::Windows, Platform::Redox), Some(defs::redox::EWOULDBLOCK));
+    assert_eq!(translate(0, Platform::Linux, Platform::Apple), None);
+    assert_eq!(translate(defs::linux_powerpc::EDEADLOCK, Platform::LinuxPowerPc, Platform::Linux), None);
+    assert_eq!(normalize_in(defs::linux_powerpc::EDEADLOCK, Platform::LinuxPowerPc), defs::linux_powerpc::EDEADLK);
+
+    assert_eq!(Errno::from_raw_in(defs::freebsd::ECONNRESET, Platform::FreeBsd), Some(Errno::ECONNRESET));
+    assert_eq!(Errno::ECONNRESET.raw_in(Platform::CURRENT), Some(Errno::ECONNRESET.raw()));
+    #[cfg(not(windows))]
+    {
+        assert_eq!(Errno::from_raw_in(defs::linux::ESTALE, Platform::Linux), Some(Errno::ESTALE));
+        assert_eq!(Errno::ESTALE.raw_in(Platform::Windows), None);
+    }
+    #[cfg(not(any(target_os = "freebsd", target_os = "dragonfly", target_os = "openbsd", target_os = "wasi")))]
+    assert_eq!(Errno::ETIME.raw_in(Platform::FreeBsd), None);
+    assert_eq!(translate(defs::linux::ETIME, Platform::Linux, Platform::Wasi), None);
+    assert_eq!(translate(defs::linux::ETIME, Platform::Linux, Platform::Apple), Some(defs::apple::ETIME));
+    assert_eq!(translate(defs::linux_mips::EDQUOT, Platform::LinuxMips, Platform::Linux), Some(defs::linux::EDQUOT));
+
+    assert_eq!(ErrorCode::new_posix(defs::ENOENT).to_portable(), Some(ErrorCode::new_posix(defs::linux::ENOENT)));
+    assert_eq!(ErrorCode::new_posix(0).to_portable(), None);
+    assert_eq!(ErrorCode::new(defs::ENOENT, &VENDOR_CATEGORY).to_portable(), None);
+}
+
+#[test]
+fn check_normalize() {
+    use error_code::posix::{normalize, normalize_in, Platform};
+
+    assert_eq!(normalize(defs::EWOULDBLOCK), defs::EAGAIN);
+    assert_eq!(normalize(defs::EOPNOTSUPP), defs::ENOTSUP);
+    assert_eq!(normalize_in(defs::linux::EDEADLOCK, Platform::Linux), defs::linux::EDEADLK);
+    assert_eq!(normalize(defs::ECONNRESET), defs::ECONNRESET);
+    assert_eq!(normalize_in(defs::windows::EWOULDBLOCK, Platform::Windows), defs::windows::EAGAIN);
+    assert_eq!(normalize_in(defs::apple::EOPNOTSUPP, Platform::Apple), defs::apple::ENOTSUP);
+    assert_eq!(normalize_in(defs::solarish::EDEADLOCK, Platform::Solarish), defs::solarish::EDEADLK);
+    assert_eq!(normalize_in(defs::apple::EAGAIN, Platform::Apple), defs::apple::EAGAIN);
+
+    let would_block = ErrorCode::new_posix(defs::EWOULDBLOCK);
+    assert!(would_block.eq_normalized(&ErrorCode::new_posix(defs::EAGAIN)));
+    assert!(!would_block.eq_normalized(&ErrorCode::new_posix(defs::EINTR)));
+    assert_eq!(would_block.normalize(), ErrorCode::new_posix(defs::EAGAIN));
+    #[cfg(not(windows))]
+    assert!(ErrorCode::new_system(defs::EOPNOTSUPP).eq_normalized(&ErrorCode::new_system(defs::ENOTSUP)));
+    assert!(!would_block.eq_normalized(&ErrorCode::new(defs::EAGAIN, &VENDOR_CATEGORY)));
+
+    let vendor = ErrorCode::new(defs::EWOULDBLOCK, &VENDOR_CATEGORY);
+    assert_eq!(vendor.normalize().raw_code(), defs::EWOULDBLOCK);
+}
+
+#[test]
+fn check_win32_category() {
+    use error_code::win32::{self, WIN32_CATEGORY};
+    use error_code::posix::Errno;
+
+    let error = ErrorCode::new(win32::ERROR_ACCESS_DENIED, &WIN32_CATEGORY);
+    assert_eq!(error.to_string(), "Win32Error(5): Access is denied.");
+    assert_eq!(error.message(), "Access is denied.");
+    assert!(!error.is_would_block());
+    assert_eq!(error, ErrorCode::new(5, &WIN32_CATEGORY));
+    assert_ne!(error, ErrorCode::new_posix(5));
+    #[cfg(windows)]
+    assert_eq!(error, ErrorCode::new_system(5));
+    #[cfg(not(windows))]
+    assert_ne!(error, ErrorCode::new_system(5));
+
+    let error = ErrorCode::new(10035, &WIN32_CATEGORY);
+    assert!(error.is_would_block());
+    assert_eq!(win32::name(10035), Some("WSAEWOULDBLOCK"));
+    assert_eq!(win32::description(11001), Some("No such host is known."));
+    assert_eq!(win32::name(99999), None);
+    assert_eq!(ErrorCode::new(99999, &WIN32_CATEGORY).message(), "Operation failed");
+
+    let mut buf = [core::mem::MaybeUninit::uninit(); error_code::MESSAGE_BUF_SIZE];
+    assert_eq!((WIN32_CATEGORY.message)(win32::ERROR_FILE_NOT_FOUND, &mut buf), "The system cannot find the file specified.");
+
+    assert_eq!(win32::to_errno(win32::ERROR_FILE_NOT_FOUND), Errno::ENOENT);
+    assert_eq!(win32::to_errno(win32::ERROR_SHARING_VIOLATION), Errno::EACCES);
+    assert_eq!(win32::to_errno(win32::ERROR_BAD_EXE_FORMAT), Errno::ENOEXEC);
+    assert_eq!(win32::to_errno(win32::ERROR_ALREADY_EXISTS), Errno::EEXIST);
+    assert_eq!(win32::to_errno(win32::WSAEWOULDBLOCK), Errno::EWOULDBLOCK);
+    assert_eq!(win32::to_errno(win32::WSAECONNRESET), Errno::ECONNRESET);
+    assert_eq!(win32::to_errno(win32::ERROR_NOT_SUPPORTED), Errno::EINVAL);
+    assert_eq!(win32::try_to_errno(win32::ERROR_NOT_SUPPORTED), None);
+    assert_eq!(win32::try_to_errno(win32::ERROR_INVALID_PARAMETER), Some(Errno::EINVAL));
+    assert_eq!(win32::try_to_errno(win32::ERROR_ACCESS_DENIED), Some(Errno::EACCES));
+}
+
+#[test]
+fn check_hresult_category() {
+    use error_code::hresult::{self, HResult, Severity, HRESULT_CATEGORY};
+    use error_code::win32::{self, WIN32_CATEGORY};
+
+    let error = HResult::new(hresult::E_FAIL);
+    assert_eq!(error.severity(), Severity::Failure);
+    assert!(error.is_failure());
+    assert!(!error.is_customer());
+    assert_eq!(error.facility(), 0);
+    assert_eq!(error.code(), 0x4005);
+    assert_eq!(error.facility_name(), Some("FACILITY_NULL"));
+    assert_eq!(error.name(), Some("E_FAIL"));
+    assert_eq!(error.to_string(), "0x80004005");
+    assert_eq!(format!("{:?}", error), "HResult(0x80004005)");
+    assert_eq!(format!("{:x}", error), "80004005");
+    assert_eq!(error.to_win32(), None);
+    assert_eq!(HResult::from_parts(Severity::Failure, 0, 0x4005), error);
+
+    let error = ErrorCode::from(error);
+    assert_eq!(error.to_string(), format!("HResult({}): 0x80004005 FACILITY_NULL: Unspecified failure", hresult::E_FAIL));
+
+    let error = HResult::from_win32(win32::ERROR_FILE_NOT_FOUND);
+    assert_eq!(error.raw(), 0x80070002u32 as i32);
+    assert_eq!(error.facility(), hresult::FACILITY_WIN32);
+    assert_eq!(error.name(), None);
+    assert_eq!(error.description(), win32::description(win32::ERROR_FILE_NOT_FOUND));
+    assert_eq!(error.to_win32(), Some(ErrorCode::new(win32::ERROR_FILE_NOT_FOUND, &WIN32_CATEGORY)));
+    assert_eq!(HResult::from_win32(win32::ERROR_ACCESS_DENIED).raw(), hresult::E_ACCESSDENIED);
+    assert_eq!(HResult::from_win32(0).raw(), hresult::S_OK);
+    assert_eq!(HResult::new(hresult::S_OK).to_win32(), Some(ErrorCode::new(0, &WIN32_CATEGORY)));
+    assert_eq!(HResult::new(hresult::S_FALSE).severity(), Severity::Success);
+    assert_eq!(HResult::new(hresult::S_FALSE).to_win32(), None);
+
+    let unknown = HResult::from_parts(Severity::Failure, 0x7ff, 1);
+    assert!(unknown.facility_name().is_none());
+    assert_eq!(ErrorCode::from(unknown).message(), "0x87FF0001 FACILITY_2047: Operation failed");
+    assert!(HResult::from_win32(win32::WSAEWOULDBLOCK).into_error_code().is_would_block());
+    assert!(ErrorCode::new(hresult::E_PENDING, &HRESULT_CATEGORY).is_would_block());
+    assert_ne!(ErrorCode::new(2, &HRESULT_CATEGORY), ErrorCode::new(2, &WIN32_CATEGORY));
+}
+
+#[test]
+fn check_ntstatus_category() {
+    use error_code::ntstatus::{self, NtStatus, Severity, NTSTATUS_CATEGORY};
+    use error_code::win32::{self, WIN32_CATEGORY};
+
+    let status = NtStatus::new(ntstatus::STATUS_ACCESS_VIOLATION);
+    assert_eq!(status.severity(), Severity::Error);
+    assert!(status.is_error());
+    assert!(!status.is_customer());
+    assert_eq!(status.facility(), 0);
+    assert_eq!(status.code(), 5);
+    assert_eq!(status.to_string(), "0xC0000005");
+    assert_eq!(format!("{:?}", status), "NtStatus(0xC0000005)");
+    assert_eq!(status.to_win32(), ErrorCode::new(win32::ERROR_NOACCESS, &WIN32_CATEGORY));
+    assert_eq!(ErrorCode::from(status).to_string(), format!("NtStatus({}): 0xC0000005: The instruction referenced memory that could not be accessed", ntstatus::STATUS_ACCESS_VIOLATION));
+
+    assert_eq!(NtStatus::new(ntstatus::STATUS_SUCCESS).severity(), Severity::Success);
+    assert_eq!(NtStatus::new(0x40000000).severity(), Severity::Informational);
+    assert_eq!(NtStatus::new(ntstatus::STATUS_BUFFER_OVERFLOW).severity(), Severity::Warning);
+    assert_eq!(NtStatus::new(ntstatus::STATUS_SUCCESS).to_win32(), ErrorCode::new(0, &WIN32_CATEGORY));
+    assert_eq!(NtStatus::new(ntstatus::STATUS_OBJECT_NAME_NOT_FOUND).to_win32().raw_code(), win32::ERROR_FILE_NOT_FOUND);
+    assert_eq!(NtStatus::new(ntstatus::STATUS_BREAKPOINT).to_win32().raw_code(), win32::ERROR_MR_MID_NOT_FOUND);
+
+    //FACILITY_NTWIN32
+    let status = NtStatus::new(0xC0070002u32 as i32);
+    assert_eq!(status.facility(), ntstatus::FACILITY_NTWIN32);
+    assert_eq!(status.name(), None);
+    assert_eq!(status.description(), win32::description(win32::ERROR_FILE_NOT_FOUND));
+    assert_eq!(status.to_win32().raw_code(), win32::ERROR_FILE_NOT_FOUND);
+
+    let unknown = ErrorCode::new(0xC0DE0001u32 as i32, &NTSTATUS_CATEGORY);
+    assert_eq!(unknown.message(), "0xC0DE0001: Operation failed");
+    assert!(ErrorCode::new(ntstatus::STATUS_PENDING, &NTSTATUS_CATEGORY).is_would_block());
+}
+
+#[test]
+fn check_netdb_category() {
+    use error_code::NETDB_CATEGORY;
+    use error_code::netdb::{self, HOST_ERROR_CATEGORY};
+
+    #[cfg(unix)]
+    {
+        assert_eq!(netdb::EAI_AGAIN, libc::EAI_AGAIN);
+        assert_eq!(netdb::EAI_BADFLAGS, libc::EAI_BADFLAGS);
+        assert_eq!(netdb::EAI_FAIL, libc::EAI_FAIL);
+        assert_eq!(netdb::EAI_FAMILY, libc::EAI_FAMILY);
+        assert_eq!(netdb::EAI_MEMORY, libc::EAI_MEMORY);
+        assert_eq!(netdb::EAI_NONAME, libc::EAI_NONAME);
+        assert_eq!(netdb::EAI_SERVICE, libc::EAI_SERVICE);
+        assert_eq!(netdb::EAI_SOCKTYPE, libc::EAI_SOCKTYPE);
+        assert_eq!(netdb::EAI_SYSTEM, libc::EAI_SYSTEM);
+        assert_eq!(netdb::EAI_OVERFLOW, libc::EAI_OVERFLOW);
+
+        let expected = unsafe {
+            std::ffi::CStr::from_ptr(libc::gai_strerror(libc::EAI_NONAME))
+        };
+        let error = ErrorCode::new(netdb::EAI_NONAME, &NETDB_CATEGORY);
+        assert_eq!(error.message(), expected.to_str().unwrap());
+
+        //EAI_SYSTEM takes actual error from errno
+        let res = unsafe {
+            libc::close(-1)
+        };
+        assert_eq!(res, -1);
+        assert_eq!(netdb::gai_error(netdb::EAI_SYSTEM), ErrorCode::new_posix(defs::EBADF));
+    }
+
+    let error = netdb::gai_error(netdb::EAI_AGAIN);
+    assert!(error.is_category(&NETDB_CATEGORY));
+    assert!(error.is_would_block());
+    assert!(!netdb::gai_error(netdb::EAI_NONAME).is_would_block());
+    assert_eq!(netdb::name(netdb::EAI_NONAME), Some("EAI_NONAME"));
+    assert_eq!(netdb::description(netdb::EAI_NONAME), Some("Name or service not known"));
+    assert_eq!(netdb::name(0), None);
+
+    assert_eq!(netdb::host_name(netdb::NO_DATA), Some("NO_DATA"));
+    assert_eq!(netdb::NO_ADDRESS, netdb::NO_DATA);
+    let error = ErrorCode::new(netdb::HOST_NOT_FOUND, &HOST_ERROR_CATEGORY);
+    assert_eq!(error.to_string(), format!("HostError({}): Unknown host", netdb::HOST_NOT_FOUND));
+    assert!(!error.is_would_block());
+    assert!(ErrorCode::new(netdb::TRY_AGAIN, &HOST_ERROR_CATEGORY).is_would_block());
+    assert_ne!(error, ErrorCode::new(netdb::HOST_NOT_FOUND, &NETDB_CATEGORY));
+
+    let error = ErrorCode::new(netdb::EAI_NONAME, &NETDB_CATEGORY);
+    for other in [ErrorCode::new_system(netdb::EAI_NONAME), ErrorCode::new(netdb::EAI_NONAME, &error_code::WIN32_CATEGORY)] {
+        assert_eq!(error == other, cfg!(windows));
+        assert_eq!(other == error, cfg!(windows));
+    }
+}
+
+#[test]
+fn check_kernel_category() {
+    use error_code::KERNEL_CATEGORY;
+    use error_code::kernel;
+
+    assert_eq!(ErrorCode::from_neg_errno(0), None);
+    assert_eq!(ErrorCode::from_neg_errno(4096), None);
+    assert_eq!(ErrorCode::from_neg_errno(-defs::EAGAIN), Some(ErrorCode::new_posix(defs::EAGAIN)));
+    assert!(ErrorCode::from_neg_errno(-defs::EAGAIN).unwrap().is_would_block());
+    assert_eq!(ErrorCode::from_neg_errno(-defs::ENOENT).unwrap().as_errno(), Some(error_code::posix::Errno::ENOENT));
+    assert_eq!(ErrorCode::from_neg_errno(-kernel::MAX_ERRNO), Some(ErrorCode::new_posix(kernel::MAX_ERRNO)));
+    assert_eq!(ErrorCode::from_neg_errno(-kernel::MAX_ERRNO - 1), None);
+    assert_eq!(ErrorCode::from_neg_errno(i32::MIN), None);
+
+    let error = ErrorCode::from_neg_errno(-kernel::ERESTARTSYS).unwrap();
+    assert!(error.is_category(&KERNEL_CATEGORY));
+    assert_eq!(error.raw_code(), 512);
+    assert_eq!(error.to_string(), "KernelError(512): Restart system call if signal handler has SA_RESTART");
+    assert_eq!(kernel::name(kernel::ENOIOCTLCMD), Some("ENOIOCTLCMD"));
+    assert_eq!(kernel::description(kernel::ENOTSUPP), Some("Operation is not supported"));
+    assert_eq!(kernel::name(520), None);
+    assert!(!kernel::is_internal(defs::EPERM));
+    assert!(!kernel::is_internal(520));
+    assert!(kernel::is_internal(kernel::ENOGRACE));
+    assert_eq!(ErrorCode::from_neg_errno(-520), Some(ErrorCode::new_posix(520)));
+    assert!(ErrorCode::new(kernel::EJUKEBOX, &KERNEL_CATEGORY).is_would_block());
+    assert_ne!(ErrorCode::new(kernel::ENOTSUPP, &KERNEL_CATEGORY), ErrorCode::new_posix(kernel::ENOTSUPP));
+
+    assert_eq!(kernel::result(0), Ok(0));
+    assert_eq!(kernel::result(4096), Ok(4096));
+    assert_eq!(kernel::result(i32::MIN), Ok(0x8000_0000));
+    assert_eq!(kernel::result(-defs::ECANCELED), Err(ErrorCode::new_posix(defs::ECANCELED)));
+    assert_eq!(kernel::result(-kernel::EIOCBQUEUED), Err(ErrorCode::new(kernel::EIOCBQUEUED, &KERNEL_CATEGORY)));
+}
+
+#[test]
+fn check_signal_category() {
+    use error_code::SIGNAL_CATEGORY;
+    use error_code::signal;
+
+    #[cfg(unix)]
+    {
+        assert_eq!(signal::SIGHUP, libc::SIGHUP);
+        assert_eq!(signal::SIGINT, libc::SIGINT);
+        assert_eq!(signal::SIGQUIT, libc::SIGQUIT);
+        assert_eq!(signal::SIGILL, libc::SIGILL);
+        assert_eq!(signal::SIGTRAP, libc::SIGTRAP);
+        assert_eq!(signal::SIGABRT, libc::SIGABRT);
+        assert_eq!(signal::SIGBUS, libc::SIGBUS);
+        assert_eq!(signal::SIGFPE, libc::SIGFPE);
+        assert_eq!(signal::SIGKILL, libc::SIGKILL);
+        assert_eq!(signal::SIGUSR1, libc::SIGUSR1);
+        assert_eq!(signal::SIGSEGV, libc::SIGSEGV);
+        assert_eq!(signal::SIGUSR2, libc::SIGUSR2);
+        assert_eq!(signal::SIGPIPE, libc::SIGPIPE);
+        assert_eq!(signal::SIGALRM, libc::SIGALRM);
+        assert_eq!(signal::SIGTERM, libc::SIGTERM);
+        assert_eq!(signal::SIGCHLD, libc::SIGCHLD);
+        assert_eq!(signal::SIGCONT, libc::SIGCONT);
+        assert_eq!(signal::SIGSTOP, libc::SIGSTOP);
+        assert_eq!(signal::SIGTSTP, libc::SIGTSTP);
+        assert_eq!(signal::SIGTTIN, libc::SIGTTIN);
+        assert_eq!(signal::SIGTTOU, libc::SIGTTOU);
+        assert_eq!(signal::SIGURG, libc::SIGURG);
+        assert_eq!(signal::SIGXCPU, libc::SIGXCPU);
+        assert_eq!(signal::SIGXFSZ, libc::SIGXFSZ);
+        assert_eq!(signal::SIGVTALRM, libc::SIGVTALRM);
+        assert_eq!(signal::SIGPROF, libc::SIGPROF);
+        assert_eq!(signal::SIGWINCH, libc::SIGWINCH);
+        assert_eq!(signal::SIGIO, libc::SIGIO);
+        assert_eq!(signal::SIGSYS, libc::SIGSYS);
+
+        let expected = unsafe {
+            std::ffi::CStr::from_ptr(libc::strsignal(libc::SIGTERM))
+        };
+        let error = ErrorCode::new(signal::SIGTERM, &SIGNAL_CATEGORY);
+        assert_eq!(error.message(), expected.to_str().unwrap());
+    }
+
+    assert_eq!(signal::name(signal::SIGSEGV), Some("SIGSEGV"));
+    assert_eq!(signal::description(signal::SIGSEGV), Some("Segmentation fault"));
+    assert_eq!(signal::name(signal::SIGIOT), Some("SIGABRT"));
+    assert_eq!(signal::name(0), None);
+
+    let error = ErrorCode::new(signal::SIGABRT, &SIGNAL_CATEGORY);
+    assert!(!error.is_would_block());
+    assert_ne!(error, ErrorCode::new_posix(signal::SIGABRT));
+}
+
+#[test]
+fn check_exit_category() {
+    use error_code::EXIT_CATEGORY;
+    use error_code::exit;
+
+    let error = ErrorCode::new(exit::EX_NOPERM, &EXIT_CATEGORY);
+    assert_eq!(exit::name(error.raw_code()), Some("EX_NOPERM"));
+    assert_eq!(error.to_string(), "ExitCode(77): Permission denied");
+    assert!(!error.is_would_block());
+    assert!(!ErrorCode::new(exit::EX_TEMPFAIL, &EXIT_CATEGORY).is_would_block());
+    assert_eq!(exit::name(1), None);
+    assert_eq!(ErrorCode::new(1, &EXIT_CATEGORY).message(), "Process exited with failure status");
+    assert_ne!(error, ErrorCode::new_posix(exit::EX_NOPERM));
+}
+
+#[cfg(all(unix, feature = "std"))]
+#[test]
+fn check_from_exit_status() {
+    use error_code::{EXIT_CATEGORY, SIGNAL_CATEGORY};
+    use error_code::{exit, signal};
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::ExitStatus;
+
+    assert_eq!(ErrorCode::from_exit_status(ExitStatus::from_raw(0)), None);
+    assert_eq!(ErrorCode::from_exit_status(ExitStatus::from_raw(exit::EX_USAGE << 8)), Some(ErrorCode::new(exit::EX_USAGE, &EXIT_CATEGORY)));
+    assert_eq!(ErrorCode::from_exit_status(ExitStatus::from_raw(signal::SIGKILL)), Some(ErrorCode::new(signal::SIGKILL, &SIGNAL_CATEGORY)));
+    //Stopped by SIGSTOP
+    let stopped = ErrorCode::from_exit_status(ExitStatus::from_raw((signal::SIGSTOP << 8) | 0x7f)).expect("to be error");
+    assert_eq!(stopped, ErrorCode::new(exit::NOT_EXITED, &EXIT_CATEGORY));
+    assert_eq!(stopped.to_string(), "ExitCode(-1): Process has not exited");
+
+    let status = std::process::Command::new("sh").arg("-c").arg("exit 3").status().expect("to run sh");
+    assert_eq!(ErrorCode::from_exit_status(status), Some(ErrorCode::new(3, &EXIT_CATEGORY)));
+}
+
+#[cfg(all(feature = "std", windows))]
+#[test]
+fn check_from_exit_status_windows() {
+    use error_code::{EXIT_CATEGORY, NTSTATUS_CATEGORY};
+    use error_code::ntstatus;
+    use std::os::windows::process::ExitStatusExt;
+    use std::process::ExitStatus;
+
+    assert_eq!(ErrorCode::from_exit_status(ExitStatus::from_raw(0)), None);
+    assert_eq!(ErrorCode::from_exit_status(ExitStatus::from_raw(2)), Some(ErrorCode::new(2, &EXIT_CATEGORY)));
+    assert_eq!(ErrorCode::from_exit_status(ExitStatus::from_raw(ntstatus::STATUS_ACCESS_VIOLATION as u32)), Some(ErrorCode::new(ntstatus::STATUS_ACCESS_VIOLATION, &NTSTATUS_CATEGORY)));
+    //exit(-1) has error severity, but it is not NTSTATUS
+    assert_eq!(ErrorCode::from_exit_status(ExitStatus::from_raw(u32::MAX)), Some(ErrorCode::new(-1, &EXIT_CATEGORY)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_report() {
+    use error_code::{EXIT_CATEGORY, SIGNAL_CATEGORY, ExitError, Report, ResultExt};
+    use error_code::posix::Errno;
+    use error_code::{exit, signal};
+    use std::process::Termination;
+
+    assert_eq!(ExitError::from(ErrorCode::new_posix(defs::ENOENT)).exit_code(), exit::EX_NOINPUT as u8);
+    assert_eq!(ExitError::from(ErrorCode::new_posix(defs::EACCES)).exit_code(), exit::EX_NOPERM as u8);
+    assert_eq!(ExitError::from(ErrorCode::new_posix(defs::EWOULDBLOCK)).exit_code(), exit::EX_TEMPFAIL as u8);
+    assert_eq!(ExitError::from(ErrorCode::new_posix(defs::ENOTEMPTY)).exit_code(), 1);
+    assert_eq!(ExitError::from(ErrorCode::new(exit::EX_CONFIG, &EXIT_CATEGORY)).exit_code(), exit::EX_CONFIG as u8);
+    assert_eq!(ExitError::from(ErrorCode::new(300, &EXIT_CATEGORY)).exit_code(), 1);
+    assert_eq!(ExitError::from(ErrorCode::new(signal::SIGTERM, &SIGNAL_CATEGORY)).exit_code(), 128 + signal::SIGTERM as u8);
+    assert_eq!(ExitError::from(ErrorCode::new(defs::ENOENT, &VENDOR_CATEGORY)).exit_code(), 1);
+
+    use error_code::{win32, WIN32_CATEGORY};
+    assert_eq!(ExitError::from(ErrorCode::new(win32::ERROR_ACCESS_DENIED, &WIN32_CATEGORY)).exit_code(), exit::EX_NOPERM as u8);
+    assert_eq!(ExitError::from(ErrorCode::new(win32::ERROR_FILE_NOT_FOUND, &WIN32_CATEGORY)).exit_code(), exit::EX_NOINPUT as u8);
+    assert_eq!(ExitError::from(ErrorCode::new(win32::ERROR_NOT_SUPPORTED, &WIN32_CATEGORY)).exit_code(), 1);
+
+    #[cfg(windows)]
+    {
+        assert_eq!(ExitError::from(ErrorCode::new_system(win32::ERROR_ACCESS_DENIED)).exit_code(), exit::EX_NOPERM as u8);
+        assert_eq!(ExitError::from(ErrorCode::new_system(win32::ERROR_NOT_SUPPORTED)).exit_code(), 1);
+        let error = ExitError::from(std::io::Error::from_raw_os_error(win32::ERROR_ACCESS_DENIED));
+        assert_eq!(error.code(), ErrorCode::new_system(win32::ERROR_ACCESS_DENIED));
+        assert_eq!(error.exit_code(), exit::EX_NOPERM as u8);
+    }
+    #[cfg(not(windows))]
+    {
+        let error = ExitError::from(std::io::Error::from_raw_os_error(defs::EACCES));
+        assert_eq!(error.code(), ErrorCode::new_system(defs::EACCES));
+        assert_eq!(error.exit_code(), exit::EX_NOPERM as u8);
+    }
+    assert_eq!(ExitError::from(std::io::Error::other("Custom")).exit_code(), 1);
+
+    const TABLE: &[(Errno, u8)] = &[(Errno::ENOTEMPTY, 10)];
+    let error = ExitError::from(ErrorCode::new_posix(defs::ENOTEMPTY)).with_table(TABLE);
+    assert_eq!(error.exit_code(), 10);
+    assert_eq!(error.with_table(&[]).exit_code(), 1);
+
+    let error = Err::<(), _>(ErrorCode::new_posix(defs::ENOENT)).context("Opening config").unwrap_err();
+    let error = ExitError::from(error);
+    assert_eq!(error.context(), Some("Opening config"));
+    assert_eq!(error.code(), ErrorCode::new_posix(defs::ENOENT));
+    assert_eq!(error.to_string(), format!("Opening config: {}", ErrorCode::new_posix(defs::ENOENT)));
+
+    let report = Report::from(Err::<(), _>(ErrorCode::new_posix(defs::ENOTEMPTY))).with_table(TABLE);
+    assert_eq!(report.into_result().unwrap_err().exit_code(), 10);
+    assert_eq!(Report::from(Ok::<(), ErrorCode>(())).report(), std::process::ExitCode::SUCCESS);
+    assert_eq!(Report::from(Err::<(), _>(ErrorCode::new_posix(defs::EPERM))).report(), std::process::ExitCode::from(exit::EX_NOPERM as u8));
+}
+
+mod glob_import {
+    #[allow(unused_imports)]
+    use error_code::*;
+
+    #[test]
+    fn check_std_matches_is_not_shadowed() {
+        assert!(matches!(Some(1), Some(1 | 2)));
+    }
+}